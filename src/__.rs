@@ -48,6 +48,15 @@ impl<T: ?Sized> TypeMarker<T> {
     pub fn expect_init<'s>(&self, _: &'s Scope<'s>, init: Init<ScopedMem<'s, T>>) {
         core::mem::forget(init)
     }
+
+    #[inline(always)]
+    pub fn expect_try_init<'s, E>(
+        &self,
+        _: &'s Scope<'s>,
+        init: Result<Init<ScopedMem<'s, T>>, E>,
+    ) -> Result<(), E> {
+        init.map(core::mem::forget)
+    }
 }
 
 #[macro_export]
@@ -72,3 +81,280 @@ macro_rules! emplace {
         unsafe { $crate::Place::assume_init($place) }
     }};
 }
+
+/// Like [`emplace!`], but `$block` returns a `Result`. On error, the place is dropped
+/// and the error is returned.
+///
+/// As with [`emplace!`], `$block` is not a closure: `return`, `break`, `continue`, `?`
+/// and `.await` apply to the enclosing function, loop or async block.
+#[macro_export]
+macro_rules! try_emplace {
+    ($slot:expr => $place:ident $block:block) => {{
+        let $place = $slot;
+        // SAFETY: same as `emplace!`; on error, the place gets dropped.
+        let (mut $place, _type) = $crate::__::TypeMarker::capture_type($place);
+        let result = {
+            $crate::let_scope!(scope);
+            let $place = scope.borrow(&mut $place);
+            _type.expect_try_init(&scope, $block)
+        };
+        match result {
+            Ok(()) => Ok(unsafe { $crate::Place::assume_init($place) }),
+            Err(err) => Err(err),
+        }
+    }};
+}
+
+/// Declares a struct implementing [`Project`](crate::project::Project), along with
+/// the struct holding its field places.
+///
+/// ```
+/// tinit::project! {
+///     #[project = ConfigFields]
+///     pub struct Config {
+///         pub name: [u8; 64],
+///         pub table: [u32; 4096],
+///     }
+/// }
+/// ```
+///
+/// Generic structs are not supported.
+#[macro_export]
+macro_rules! project {
+    (
+        #[project = $fields:ident]
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$fattr:meta])*
+                $fvis:vis $field:ident : $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$fattr])*
+                $fvis $field: $ty,
+            )*
+        }
+
+        #[doc = concat!("Field places of [`", stringify!($name), "`].")]
+        #[allow(dead_code)]
+        $vis struct $fields<'a> {
+            $(
+                $fvis $field: $crate::project::FieldMem<'a, $ty>,
+            )*
+        }
+
+        unsafe impl $crate::project::Project for $name {
+            type Flags = [bool; <[&str]>::len(&[$(stringify!($field)),*])];
+
+            const UNINIT: Self::Flags = [false; <[&str]>::len(&[$(stringify!($field)),*])];
+
+            type Fields<'a> = $fields<'a>;
+
+            #[inline(always)]
+            unsafe fn project<'a>(
+                mut this: $crate::uninit::UninitMut<'a, Self>,
+                flags: &'a mut Self::Flags,
+            ) -> $fields<'a> {
+                let ptr = this.as_mut_ptr();
+                let [$($field),*] = flags;
+                $fields {
+                    $(
+                        $field: unsafe {
+                            $crate::project::FieldMem::new(
+                                $crate::uninit::UninitMut::new_unchecked(
+                                    ::core::ptr::addr_of_mut!((*ptr).$field),
+                                ),
+                                $field,
+                            )
+                        },
+                    )*
+                }
+            }
+
+            #[inline]
+            unsafe fn drop_fields(mut this: $crate::uninit::UninitMut<'_, Self>, flags: &Self::Flags) {
+                let ptr = this.as_mut_ptr();
+                let [$($field),*] = flags;
+                $(
+                    if *$field {
+                        unsafe { ::core::ptr::drop_in_place(::core::ptr::addr_of_mut!((*ptr).$field)) }
+                    }
+                )*
+            }
+        }
+
+        // Field places must be aligned, so reject `#[repr(packed)]`.
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_not_packed(this: &$name) {
+                $(let _ = &this.$field;)*
+            }
+        };
+    };
+}
+
+/// Implements [`Zeroable`](crate::zeroable::Zeroable) for a struct whose fields are
+/// all `Zeroable`.
+///
+/// ```
+/// struct Header {
+///     magic: u32,
+///     flags: [u8; 12],
+/// }
+///
+/// tinit::derive_zeroable!(Header { magic, flags });
+/// ```
+///
+/// All fields must be listed. Generic structs are not supported.
+#[macro_export]
+macro_rules! derive_zeroable {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        // SAFETY: every field is checked to be zeroable below.
+        unsafe impl $crate::zeroable::Zeroable for $name {}
+
+        const _: () = {
+            fn assert_zeroable<T: $crate::zeroable::Zeroable + ?Sized>(_: &T) {}
+
+            #[allow(dead_code)]
+            fn assert_fields(this: &$name) {
+                // Fails to compile if a field is missing.
+                let $name { $($field),* } = this;
+                $(assert_zeroable($field);)*
+            }
+        };
+    };
+}
+
+/// Implements [`CloneInto`](crate::clone::CloneInto) for a struct.
+///
+/// Structs declared with [`project!`](crate::project!) can list their fields, which are
+/// then cloned into their own place one by one. Otherwise, the struct's [`Clone`] impl is
+/// used.
+///
+/// ```
+/// use tinit::clone::CloneInto;
+/// use tinit::Emplace;
+///
+/// tinit::project! {
+///     #[project = ConfigFields]
+///     pub struct Config {
+///         pub name: [u8; 64],
+///         pub table: [u32; 4096],
+///     }
+/// }
+///
+/// tinit::derive_clone_into!(Config { name, table });
+///
+/// #[derive(Clone, Copy)]
+/// pub struct Small(u32);
+///
+/// tinit::derive_clone_into!(Small);
+///
+/// let small: Box<Small> = Small(3).clone_into_place(Box::emplace());
+/// assert_eq!(small.0, 3);
+/// ```
+///
+/// All fields must be listed. Generic structs are not supported.
+#[macro_export]
+macro_rules! derive_clone_into {
+    ($name:ident) => {
+        impl $crate::clone::CloneInto for $name {
+            #[inline(always)]
+            fn clone_into_place<V>(&self, slot: impl $crate::Slot<V>) -> V
+            where
+                V: ::core::ops::Deref<Target = Self>,
+            {
+                $crate::Slot::set(slot, <$name as ::core::clone::Clone>::clone(self))
+            }
+        }
+    };
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::clone::CloneInto for $name {
+            #[inline]
+            fn clone_into_place<V>(&self, slot: impl $crate::Slot<V>) -> V
+            where
+                V: ::core::ops::Deref<Target = Self>,
+            {
+                // Fails to compile if a field is missing.
+                let $name { $($field),* } = self;
+                let mut projection = $crate::project::Projection::new($crate::Slot::into_place(slot));
+                let fields = projection.fields();
+                $($crate::clone::CloneInto::clone_into_place($field, fields.$field);)*
+                projection.finish()
+            }
+        }
+    };
+}
+
+/// Implements [`DefaultInPlace`](crate::default::DefaultInPlace) for a struct.
+///
+/// Structs declared with [`project!`](crate::project!) can list their fields, which are
+/// then initialized in place one by one. Otherwise, the struct's [`Default`] impl is used.
+///
+/// ```
+/// use tinit::{Emplace, Own};
+///
+/// tinit::project! {
+///     #[project = StateFields]
+///     pub struct State {
+///         pub tick: u64,
+///         pub grid: [[u8; 1024]; 1024],
+///     }
+/// }
+///
+/// tinit::derive_default_in_place!(State { tick, grid });
+///
+/// #[derive(Default)]
+/// pub struct Small(u32);
+///
+/// tinit::derive_default_in_place!(Small);
+///
+/// let state = Box::<State>::emplace_default();
+/// assert_eq!(state.grid[1023][1023], 0);
+///
+/// let slot = &mut tinit::stack_slot::<Small>();
+/// let small = Own::default_in(slot);
+/// assert_eq!(small.0, 0);
+/// ```
+///
+/// All fields must be listed. Generic structs are not supported.
+#[macro_export]
+macro_rules! derive_default_in_place {
+    ($name:ident) => {
+        impl $crate::default::DefaultInPlace for $name {
+            #[inline(always)]
+            fn default_in_place<V>(slot: impl $crate::Slot<V>) -> V
+            where
+                V: ::core::ops::Deref<Target = Self>,
+            {
+                $crate::Slot::set(slot, <$name as ::core::default::Default>::default())
+            }
+        }
+    };
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $crate::default::DefaultInPlace for $name {
+            #[inline]
+            fn default_in_place<V>(slot: impl $crate::Slot<V>) -> V
+            where
+                V: ::core::ops::Deref<Target = Self>,
+            {
+                let mut projection = $crate::project::Projection::new($crate::Slot::into_place(slot));
+                let fields = projection.fields();
+                $($crate::default::DefaultInPlace::default_in_place(fields.$field);)*
+                projection.finish()
+            }
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fields(this: &$name) {
+                // Fails to compile if a field is missing.
+                let $name { $($field: _),* } = this;
+            }
+        };
+    };
+}
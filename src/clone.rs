@@ -0,0 +1,153 @@
+//! Cloning values directly into a place.
+//!
+//! [`CloneInto::clone_into_place`] copies `self` into any [`Slot`], e.g. a fresh
+//! `Box::emplace()`. Arrays and slices are cloned element by element, tuples and
+//! [projected](mod@crate::project) structs field by field, so only the leaves of the
+//! value are ever moved.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::init::Init;
+use crate::place::{Emplace, EmplaceSlice, SlicePlace, Slot};
+use crate::project::Projection;
+use crate::slice::Slice;
+
+/// A value which can be cloned into a place.
+///
+/// Use the [`derive_clone_into!`](crate::derive_clone_into!) macro to implement it for
+/// structs.
+pub trait CloneInto {
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>;
+}
+
+macro_rules! impl_clone_into {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl CloneInto for $ty {
+                #[inline(always)]
+                fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+                where
+                    V: Deref<Target = Self>,
+                {
+                    slot.set(self.clone())
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_into!(u8, u16, u32, u64, u128, usize);
+impl_clone_into!(i8, i16, i32, i64, i128, isize);
+impl_clone_into!(f32, f64, bool, char, (), String);
+
+impl<T: ?Sized> CloneInto for &T {
+    #[inline(always)]
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set(*self)
+    }
+}
+
+impl<T: Clone> CloneInto for Vec<T> {
+    #[inline(always)]
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set(self.clone())
+    }
+}
+
+impl<T: CloneInto> CloneInto for Box<T> {
+    #[inline]
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set((**self).clone_into_place(Box::emplace()))
+    }
+}
+
+impl<T: CloneInto> CloneInto for Box<[T]> {
+    #[inline]
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set((**self).clone_into_place(Box::emplace_slice(self.len())))
+    }
+}
+
+impl<T: CloneInto, const N: usize> CloneInto for [T; N] {
+    #[inline]
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        Init::finalize(clone_slice(self, slot.into_place()))
+    }
+}
+
+impl<T: CloneInto> CloneInto for [T] {
+    #[inline]
+    fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        Init::finalize(clone_slice(self, slot.into_place()))
+    }
+}
+
+#[inline]
+fn clone_slice<T: CloneInto, P: SlicePlace<Elem = T>>(src: &[T], place: P) -> Init<P> {
+    if src.len() != place.len() {
+        panic_len_mismatch(src.len(), place.len());
+    }
+
+    // If a clone panics, the slice drops the elements cloned so far.
+    let mut slice = Slice::new(place);
+    for elem in src {
+        elem.clone_into_place(slice.emplace());
+    }
+    slice.assert_full()
+}
+
+macro_rules! impl_clone_into_tuple {
+    ($($T:ident $idx:tt)*) => {
+        impl<$($T: CloneInto),*> CloneInto for ($($T,)*) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn clone_into_place<V>(&self, slot: impl Slot<V>) -> V
+            where
+                V: Deref<Target = Self>,
+            {
+                let mut projection = Projection::new(slot.into_place());
+                let ($($T,)*) = projection.fields();
+                $(self.$idx.clone_into_place($T);)*
+                projection.finish()
+            }
+        }
+    };
+}
+
+impl_clone_into_tuple!(A 0);
+impl_clone_into_tuple!(A 0 B 1);
+impl_clone_into_tuple!(A 0 B 1 C 2);
+impl_clone_into_tuple!(A 0 B 1 C 2 D 3);
+impl_clone_into_tuple!(A 0 B 1 C 2 D 3 E 4);
+impl_clone_into_tuple!(A 0 B 1 C 2 D 3 E 4 F 5);
+impl_clone_into_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6);
+impl_clone_into_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7);
+
+#[cold]
+#[inline(never)]
+fn panic_len_mismatch(len: usize, capacity: usize) -> ! {
+    panic!("source and destination lengths differ (len: {len}, capacity: {capacity})")
+}
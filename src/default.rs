@@ -0,0 +1,129 @@
+//! Default values constructed directly in a place.
+//!
+//! [`DefaultInPlace`] is the counterpart of [`Default`] for places, and backs
+//! [`Emplace::emplace_default`] and [`Own::default_in`](crate::Init::default_in).
+//! Leaf types write their `Default` value, while arrays, tuples and derived structs
+//! recurse into the place of each element or field.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::init::Init;
+use crate::place::{Emplace, Slot};
+use crate::project::Projection;
+use crate::slice::Slice;
+
+/// A type with a default value, which can be initialized in place.
+///
+/// Use the [`derive_default_in_place!`](crate::derive_default_in_place!) macro to
+/// implement it for structs.
+pub trait DefaultInPlace {
+    fn default_in_place<V>(slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>;
+}
+
+macro_rules! impl_default_in_place {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl DefaultInPlace for $ty {
+                #[inline(always)]
+                fn default_in_place<V>(slot: impl Slot<V>) -> V
+                where
+                    V: Deref<Target = Self>,
+                {
+                    slot.set(Default::default())
+                }
+            }
+        )*
+    };
+}
+
+impl_default_in_place!(u8, u16, u32, u64, u128, usize);
+impl_default_in_place!(i8, i16, i32, i64, i128, isize);
+impl_default_in_place!(f32, f64, bool, char, (), String);
+
+impl<T> DefaultInPlace for Vec<T> {
+    #[inline(always)]
+    fn default_in_place<V>(slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set(Vec::new())
+    }
+}
+
+impl<T> DefaultInPlace for Option<T> {
+    #[inline(always)]
+    fn default_in_place<V>(slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set(None)
+    }
+}
+
+impl<T: ?Sized> DefaultInPlace for PhantomData<T> {
+    #[inline(always)]
+    fn default_in_place<V>(slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set(PhantomData)
+    }
+}
+
+impl<T: DefaultInPlace> DefaultInPlace for Box<T> {
+    #[inline]
+    fn default_in_place<V>(slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        slot.set(Box::emplace_default())
+    }
+}
+
+impl<T: DefaultInPlace, const N: usize> DefaultInPlace for [T; N] {
+    #[inline]
+    fn default_in_place<V>(slot: impl Slot<V>) -> V
+    where
+        V: Deref<Target = Self>,
+    {
+        // If an element panics, the slice drops the elements written so far.
+        let mut slice = Slice::new(slot.into_place());
+        while !slice.is_full() {
+            T::default_in_place(slice.emplace());
+        }
+        Init::finalize(slice.assert_full())
+    }
+}
+
+macro_rules! impl_default_in_place_tuple {
+    ($($T:ident)*) => {
+        impl<$($T: DefaultInPlace),*> DefaultInPlace for ($($T,)*) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn default_in_place<V>(slot: impl Slot<V>) -> V
+            where
+                V: Deref<Target = Self>,
+            {
+                let mut projection = Projection::new(slot.into_place());
+                let ($($T,)*) = projection.fields();
+                $($T::default_in_place($T);)*
+                projection.finish()
+            }
+        }
+    };
+}
+
+impl_default_in_place_tuple!(A);
+impl_default_in_place_tuple!(A B);
+impl_default_in_place_tuple!(A B C);
+impl_default_in_place_tuple!(A B C D);
+impl_default_in_place_tuple!(A B C D E);
+impl_default_in_place_tuple!(A B C D E F);
+impl_default_in_place_tuple!(A B C D E F G);
+impl_default_in_place_tuple!(A B C D E F G H);
@@ -1,12 +1,20 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::future::Future;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ops::{Deref, DerefMut};
-use core::ptr;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::{error, fmt, ptr};
 
-use crate::mem::Mem;
-use crate::place::{Place, Slot};
+use crate::default::DefaultInPlace;
+use crate::mem::{BoxAlloc, BoxMem, Mem, ScopedMem};
+use crate::place::{CastPlace, Place};
+use crate::unsize::{Coercion, UnsizePlace};
 
-// TODO: impl all useful traits
 // TODO: document methods and safety invariants
 #[repr(transparent)]
 pub struct Init<P: Place> {
@@ -33,10 +41,30 @@ impl<P: Place> DerefMut for Init<P> {
     }
 }
 
+impl<T, P: Place<Target = T>> Init<P> {
+    #[inline(always)]
+    pub fn new(mut place: P, value: T) -> Self {
+        place.deref_place_mut().write(value);
+        // SAFETY: we just wrote the value.
+        unsafe { Self::new_unchecked(place) }
+    }
+}
+
 impl<'s, T> Init<Mem<'s, T>> {
     #[inline(always)]
-    pub fn new_in(uninit: &'s mut MaybeUninit<T>, value: T) -> Self {
-        Mem::new(uninit.into()).set(value)
+    pub const fn new_in(uninit: &'s mut MaybeUninit<T>, value: T) -> Self {
+        let value = uninit.write(value);
+        // SAFETY: we just wrote the value.
+        unsafe { Self::new_unchecked(Mem::from_raw(value)) }
+    }
+
+    /// Initializes a default value in `uninit`, without going through the stack.
+    #[inline]
+    pub fn default_in(uninit: &'s mut MaybeUninit<T>) -> Self
+    where
+        T: DefaultInPlace,
+    {
+        T::default_in_place(uninit)
     }
 }
 
@@ -44,8 +72,11 @@ impl<T: ?Sized, P> Init<P>
 where
     P: Place<Target = T>,
 {
+    /// # Safety
+    ///
+    /// `place` must contain a valid value.
     #[inline(always)]
-    pub unsafe fn new_unchecked(place: P) -> Self {
+    pub const unsafe fn new_unchecked(place: P) -> Self {
         Self {
             place,
             _marker: PhantomData,
@@ -61,11 +92,13 @@ where
     }
 
     #[inline(always)]
-    pub fn forget(this: Self) -> P {
+    pub const fn forget(this: Self) -> P {
         // Disable the drop impl.
         let this = ManuallyDrop::new(this);
+        // Go through raw pointers, as `ManuallyDrop`'s `Deref` isn't const.
+        let this = ptr::from_ref(&this).cast::<Self>();
         // SAFETY: `this` isn't accessed nor dropped after this line.
-        unsafe { ptr::read(&this.place) }
+        unsafe { ptr::read(ptr::addr_of!((*this).place)) }
     }
 
     #[inline(always)]
@@ -101,6 +134,30 @@ where
         // SAFETY: `place` contains a valid `T`.
         unsafe { Self::forget(this).assume_init() }
     }
+
+    /// Replaces the value with `f(value)`, reusing the same storage.
+    ///
+    /// If `f` panics, the place is dropped empty.
+    #[inline]
+    pub fn map_in_place<U>(this: Self, f: impl FnOnce(T) -> U) -> Init<P::Cast>
+    where
+        T: Sized,
+        P: CastPlace<U>,
+    {
+        let (value, place) = Self::take_full(this);
+        Init::new(place.cast_place(), f(value))
+    }
+
+    /// Unsizes the value in place, e.g. to a slice or a trait object.
+    #[inline(always)]
+    pub fn unsize<U: ?Sized>(this: Self, coercion: Coercion<T, U>) -> Init<P::Unsized>
+    where
+        P: UnsizePlace<U>,
+    {
+        let place = Self::forget(this).unsize_place(coercion);
+        // SAFETY: the unsized place points to the same valid value.
+        unsafe { Init::new_unchecked(place) }
+    }
 }
 
 impl<P: Place> Drop for Init<P> {
@@ -110,3 +167,228 @@ impl<P: Place> Drop for Init<P> {
         unsafe { self.place.deref_place_mut().drop_in_place() }
     }
 }
+
+// Standard traits, delegating to the target like `Box` does.
+//
+// The `Fn*` traits can't be implemented on stable, but call expressions auto-deref,
+// so `own()` still works for `Own<'_, dyn FnMut()>`.
+
+impl<P: Place> fmt::Debug for Init<P>
+where
+    P::Target: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<P: Place> fmt::Display for Init<P>
+where
+    P::Target: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<P: Place> fmt::Pointer for Init<P> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&ptr::from_ref(&**self), f)
+    }
+}
+
+impl<P: Place, Q: Place> PartialEq<Init<Q>> for Init<P>
+where
+    P::Target: PartialEq<Q::Target>,
+{
+    #[inline]
+    fn eq(&self, other: &Init<Q>) -> bool {
+        **self == **other
+    }
+}
+
+impl<P: Place> Eq for Init<P> where P::Target: Eq {}
+
+impl<P: Place, Q: Place> PartialOrd<Init<Q>> for Init<P>
+where
+    P::Target: PartialOrd<Q::Target>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Init<Q>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<P: Place> Ord for Init<P>
+where
+    P::Target: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<P: Place> Hash for Init<P>
+where
+    P::Target: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<P: Place> Hasher for Init<P>
+where
+    P::Target: Hasher,
+{
+    #[inline]
+    fn finish(&self) -> u64 {
+        (**self).finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        (**self).write(bytes)
+    }
+}
+
+// `Borrow` can't be implemented for all places, as it would overlap with the blanket
+// `impl<T> Borrow<T> for T`.
+macro_rules! impl_borrow {
+    ($([$($gen:tt)*] $place:ty),* $(,)?) => {
+        $(
+            impl<$($gen)*> Borrow<T> for Init<$place> {
+                #[inline(always)]
+                fn borrow(&self) -> &T {
+                    self
+                }
+            }
+
+            impl<$($gen)*> BorrowMut<T> for Init<$place> {
+                #[inline(always)]
+                fn borrow_mut(&mut self) -> &mut T {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_borrow!(
+    ['s, T: ?Sized] Mem<'s, T>,
+    ['s, T: ?Sized] ScopedMem<'s, T>,
+    [T: ?Sized, A: BoxAlloc] BoxMem<T, A>,
+);
+
+impl<P: Place> AsRef<P::Target> for Init<P> {
+    #[inline(always)]
+    fn as_ref(&self) -> &P::Target {
+        self
+    }
+}
+
+impl<P: Place> AsMut<P::Target> for Init<P> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut P::Target {
+        self
+    }
+}
+
+impl<P: Place> Iterator for Init<P>
+where
+    P::Target: Iterator,
+{
+    type Item = <P::Target as Iterator>::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (**self).next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        (**self).nth(n)
+    }
+}
+
+impl<P: Place> DoubleEndedIterator for Init<P>
+where
+    P::Target: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (**self).next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        (**self).nth_back(n)
+    }
+}
+
+impl<P: Place> ExactSizeIterator for Init<P>
+where
+    P::Target: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+impl<P: Place> FusedIterator for Init<P> where P::Target: FusedIterator {}
+
+// Like for `Box`, `!Unpin` futures must be pinned first, e.g. with `Slot::pin_with`.
+impl<P: Place> Future for Init<P>
+where
+    P::Target: Future + Unpin,
+{
+    type Output = <P::Target as Future>::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the target is `Unpin`, and we never move out of the place.
+        let this = unsafe { self.get_unchecked_mut() };
+        Pin::new(&mut **this).poll(cx)
+    }
+}
+
+impl<P: Place> error::Error for Init<P>
+where
+    P::Target: error::Error,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        (**self).source()
+    }
+}
+
+impl<P: Place> fmt::Write for Init<P>
+where
+    P::Target: fmt::Write,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        (**self).write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        (**self).write_char(c)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        (**self).write_fmt(args)
+    }
+}
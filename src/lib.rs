@@ -14,26 +14,31 @@ pub mod __;
 mod init;
 
 // Public modules
+pub mod clone;
+pub mod default;
 pub mod mem;
+pub mod pin;
 pub mod place;
+pub mod project;
 pub mod slice;
 pub mod uninit;
+pub mod unsize;
+pub mod vec;
+pub mod zeroable;
 
 // Reexports
 #[doc(no_inline)]
 pub use mem::{Mem, ScopedMem};
 #[doc(no_inline)]
-pub use place::{Emplace, Place, Slot};
+pub use place::{Emplace, EmplaceSlice, Place, Slot, TryEmplace};
 
 pub use init::Init;
 
 pub type Own<'s, T> = Init<Mem<'s, T>>;
 pub type ScopedRef<'s, T> = Init<mem::ScopedMem<'s, T>>;
 
-// TODO: constify everything that can be constified.
-
 #[inline(always)]
-pub fn stack_slot<T>() -> MaybeUninit<T> {
+pub const fn stack_slot<T>() -> MaybeUninit<T> {
     MaybeUninit::uninit()
 }
 
@@ -43,8 +48,31 @@ mod tests {
     use std::prelude::v1::*;
 
     use super::*;
+    use project::Projection;
     use slice::Slice;
 
+    /// Counts the drops of the [`Tracked`] values it hands out.
+    #[derive(Default)]
+    struct DropCounter(std::sync::Arc<core::sync::atomic::AtomicUsize>);
+
+    impl DropCounter {
+        fn track(&self) -> Tracked {
+            Tracked(self.0.clone())
+        }
+
+        fn count(&self) -> usize {
+            self.0.load(core::sync::atomic::Ordering::Relaxed)
+        }
+    }
+
+    struct Tracked(std::sync::Arc<core::sync::atomic::AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
     #[test]
     fn it_works() {
         let b: Box<i32> = emplace!(Box::emplace() => out {
@@ -76,6 +104,457 @@ mod tests {
         assert_eq!(numbers.first(), Some(&10610209857723));
     }
 
+    #[test]
+    fn emplace_box_slice() {
+        let mut slice = Slice::new(Box::<[u64]>::emplace_slice(100));
+        while !slice.is_full() {
+            slice.push(slice.len() as u64 * 3);
+        }
+        let numbers: Box<[u64]> = Init::finalize(slice.assert_full());
+        assert_eq!(numbers.len(), 100);
+        assert_eq!(numbers[99], 297);
+
+        let mut slice = Slice::new(Box::<[String]>::new_uninit_slice(3).into_place());
+        slice.push("a".into());
+        drop(slice);
+
+        let empty = Slice::new(Box::<[String]>::emplace_slice(0)).assert_full();
+        assert!(Init::finalize(empty).is_empty());
+    }
+
+    #[test]
+    fn custom_allocator() {
+        use core::cell::Cell;
+        use core::ptr::NonNull;
+        use mem::{AllocError, Allocator, BoxMem, Boxed};
+        use std::alloc::{GlobalAlloc, Layout, System};
+
+        #[derive(Default)]
+        struct Counting {
+            live: Cell<usize>,
+        }
+
+        unsafe impl Allocator for Counting {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                self.live.set(self.live.get() + 1);
+                NonNull::new(unsafe { System.alloc(layout) }).ok_or(AllocError)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.live.set(self.live.get() - 1);
+                unsafe { System.dealloc(ptr.as_ptr(), layout) }
+            }
+        }
+
+        let counting = Counting::default();
+        let boxed: Boxed<[u32; 64], &Counting> = emplace!(BoxMem::alloc_in(&counting) => out {
+            let mut slice = Slice::new(out);
+            while !slice.is_full() {
+                slice.push(7);
+            }
+            slice.assert_full()
+        });
+        assert_eq!(counting.live.get(), 1);
+        assert_eq!(boxed[63], 7);
+
+        let place = Init::drop(boxed);
+        assert_eq!(counting.live.get(), 1);
+        drop(place);
+        assert_eq!(counting.live.get(), 0);
+
+        let mut slice = Slice::new(BoxMem::<[String], _>::alloc_slice_in(3, &counting));
+        slice.push("tinit".into());
+        drop(slice);
+        assert_eq!(counting.live.get(), 0);
+    }
+
+    #[test]
+    fn fallible_alloc() {
+        use core::ptr::NonNull;
+        use mem::{AllocError, Allocator, BoxMem};
+        use std::alloc::Layout;
+
+        struct Exhausted;
+
+        unsafe impl Allocator for Exhausted {
+            fn allocate(&self, _: Layout) -> Result<NonNull<u8>, AllocError> {
+                Err(AllocError)
+            }
+
+            unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+                unreachable!()
+            }
+        }
+
+        assert!(BoxMem::<[u8; 16], _>::try_alloc_in(Exhausted).is_err());
+        assert!(BoxMem::<(), _>::try_alloc_in(Exhausted).is_ok());
+        assert_eq!(BoxMem::<[u64]>::try_alloc_slice(usize::MAX / 4).err(), Some(AllocError));
+        let overflow = std::panic::catch_unwind(|| BoxMem::<[u64]>::alloc_slice(usize::MAX / 4));
+        assert!(overflow.is_err());
+
+        let b: Box<u32> = Box::try_emplace().unwrap().set(3);
+        assert_eq!(*b, 3);
+    }
+
+    #[test]
+    fn reuse_box() {
+        use mem::BoxMem;
+
+        let mut frame: Box<[u32; 1 << 16]> = Box::emplace_zeroed();
+        let addr = frame.as_ptr();
+        for round in 1..4 {
+            frame = emplace!(BoxMem::from_box_drop(frame) => out {
+                out.init_from_fn(|i| i as u32 * round)
+            });
+            assert_eq!(frame.as_ptr(), addr);
+            assert_eq!(frame[100], 100 * round);
+        }
+
+        let names = Box::new([String::from("a"), String::from("b")]);
+        let (mut taken, place) = BoxMem::from_box_take(names);
+        taken.reverse();
+        let names: Box<_> = place.set(taken);
+        assert_eq!(*names, ["b", "a"]);
+
+        let slice = BoxMem::from_box_drop(vec![1u8, 2, 3].into_boxed_slice());
+        assert_eq!(*slice.init_from_fn(|i| i as u8), [0, 1, 2]);
+    }
+
+    #[test]
+    fn cast_in_place() {
+        use mem::BoxMem;
+
+        #[repr(align(4))]
+        struct RawFrame([u8; 4096]);
+        struct DecodedFrame([u32; 1024]);
+
+        fn decode(raw: RawFrame) -> DecodedFrame {
+            let mut out = [0; 1024];
+            for (word, bytes) in out.iter_mut().zip(raw.0.chunks_exact(4)) {
+                *word = u32::from_le_bytes(bytes.try_into().unwrap());
+            }
+            DecodedFrame(out)
+        }
+
+        let raw = Box::<RawFrame>::emplace().set(RawFrame([1; 4096]));
+        let addr = &*raw as *const RawFrame as usize;
+        let decoded = Init::map_in_place(BoxMem::from_box(raw), decode);
+        let decoded: Box<DecodedFrame> = Init::finalize(decoded);
+        assert_eq!(&*decoded as *const DecodedFrame as usize, addr);
+        assert_eq!(decoded.0[1023], 0x01010101);
+
+        let slot = &mut stack_slot::<u64>();
+        let own = Own::new_in(slot, u64::MAX);
+        let own = Init::map_in_place(own, |n| (n as u32, 1u16));
+        assert_eq!(*own, (u32::MAX, 1));
+
+        let slot = &mut stack_slot::<[u16; 4]>();
+        let mem = Mem::new(slot.into()).cast::<[u8; 6]>();
+        assert_eq!(*mem.set([7; 6]), [7; 6]);
+    }
+
+    #[test]
+    fn static_cell() {
+        use mem::StaticCell;
+
+        static TABLE: StaticCell<[u32; 1 << 16]> = StaticCell::new();
+        static NAME: StaticCell<String> = StaticCell::new();
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| Some(TABLE.take_place()?.init_from_fn(|i| i as u32))))
+            .collect();
+        let tables: Vec<Own<'static, _>> =
+            threads.into_iter().filter_map(|t| t.join().unwrap()).collect();
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0][1234], 1234);
+        assert!(TABLE.is_taken());
+
+        let name: &'static mut String = Init::leak(NAME.emplace().set("tinit".into()));
+        name.push('!');
+        assert_eq!(name, "tinit!");
+        assert!(std::panic::catch_unwind(|| NAME.emplace()).is_err());
+    }
+
+    #[test]
+    fn pinned_self_ref() {
+        use core::marker::PhantomPinned;
+        use core::pin::Pin;
+        use core::ptr::{addr_of, NonNull};
+
+        struct SelfRef {
+            value: u32,
+            this: *const u32,
+            _tracked: Tracked,
+            _pinned: PhantomPinned,
+        }
+
+        impl SelfRef {
+            fn new(this: NonNull<Self>, value: u32, tracked: Tracked) -> Self {
+                Self {
+                    value,
+                    this: unsafe { addr_of!((*this.as_ptr()).value) },
+                    _tracked: tracked,
+                    _pinned: PhantomPinned,
+                }
+            }
+        }
+
+        let drops = DropCounter::default();
+
+        let boxed: Pin<Box<SelfRef>> = Box::emplace().pin_with(|out| {
+            out.set_with(|this| SelfRef::new(this, 5, drops.track()))
+        });
+        assert_eq!(boxed.this, &boxed.value as *const u32);
+        assert_eq!(unsafe { *boxed.this }, 5);
+        drop(boxed);
+        assert_eq!(drops.count(), 1);
+
+        {
+            pin_slot!(slot);
+            let own = slot.pin_with(|out| {
+                out.set_with(|this| SelfRef::new(this, 7, drops.track()))
+            });
+            assert_eq!(unsafe { *own.this }, 7);
+            core::mem::forget(own);
+            assert_eq!(drops.count(), 1);
+        }
+        assert_eq!(drops.count(), 2);
+    }
+
+    fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+        use core::task::{Context, Poll, Waker};
+
+        let mut fut = core::pin::pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    /// Stand-in for an async reader, returning one byte every other poll.
+    struct SlowReader<'a> {
+        data: &'a [u8],
+        ready: bool,
+    }
+
+    impl SlowReader<'_> {
+        async fn read_byte(&mut self) -> u8 {
+            core::future::poll_fn(|cx| {
+                self.ready = !self.ready;
+                if self.ready {
+                    let (first, rest) = self.data.split_first().unwrap();
+                    self.data = rest;
+                    core::task::Poll::Ready(*first)
+                } else {
+                    cx.waker().wake_by_ref();
+                    core::task::Poll::Pending
+                }
+            })
+            .await
+        }
+    }
+
+    #[test]
+    fn with_async() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut reader = SlowReader { data: &data, ready: false };
+
+        let buf = block_on(Box::<[u8; 256]>::emplace().with_async(async |out| {
+            let mut slice = Slice::new(out);
+            while !slice.is_full() {
+                slice.push(reader.read_byte().await);
+            }
+            slice.assert_full()
+        }));
+
+        assert_eq!(&buf[..], &data[..]);
+    }
+
+    #[test]
+    fn with_async_cancel() {
+        use core::future::Future;
+        use core::task::{Context, Waker};
+
+        let drops = DropCounter::default();
+        let data = [1, 2, 3, 4];
+        let mut reader = SlowReader { data: &data, ready: false };
+
+        {
+            let fut = Box::<[Tracked; 4]>::emplace().with_async(async |out| {
+                let mut slice = Slice::new(out);
+                while !slice.is_full() {
+                    reader.read_byte().await;
+                    slice.push(drops.track());
+                }
+                slice.assert_full()
+            });
+
+            // Every poll reads a single byte.
+            let mut fut = core::pin::pin!(fut);
+            let mut cx = Context::from_waker(Waker::noop());
+            for _ in 0..3 {
+                assert!(fut.as_mut().poll(&mut cx).is_pending());
+            }
+        }
+
+        assert_eq!(drops.count(), 3);
+    }
+
+    #[test]
+    fn unsize() {
+        use core::fmt::Display;
+        use mem::{BoxMem, Boxed};
+        use unsize::Coercion;
+
+        let slot = &mut stack_slot();
+        let own: Own<'_, [u32]> = Init::unsize(Own::new_in(slot, [1, 2, 3]), Coercion::to_slice());
+        assert_eq!(&*own, &[1, 2, 3]);
+
+        let mut calls = 0;
+        let slot = &mut stack_slot();
+        let mut own = Init::unsize(
+            Own::new_in(slot, || {
+                calls += 1;
+                calls
+            }),
+            coercion!(dyn FnMut() -> u32 + '_),
+        );
+        assert_eq!(own(), 1);
+        assert_eq!(own(), 2);
+        drop(own);
+        assert_eq!(calls, 2);
+
+        let boxed = Init::unsize(Init::new(BoxMem::alloc(), 42), coercion!(dyn Display));
+        let boxed: Box<dyn Display> = Init::finalize(boxed);
+        assert_eq!(boxed.to_string(), "42");
+
+        let mut slice = Slice::new(BoxMem::<[String; 2]>::alloc().unsize(Coercion::to_slice()));
+        slice.push("a".into());
+        slice.push("b".into());
+        let strings: Boxed<[String], _> = slice.assert_full();
+        assert_eq!(Init::finalize(strings).len(), 2);
+    }
+
+    #[test]
+    fn uninit_layout() {
+        use core::alloc::Layout;
+        use core::fmt::Debug;
+        use mem::BoxMem;
+        use unsize::Coercion;
+
+        let place = BoxMem::<[u16; 5]>::alloc();
+        assert_eq!(place.deref_place().layout(), Layout::new::<[u16; 5]>());
+
+        let mut place = place.unsize(Coercion::to_slice());
+        let uninit = place.deref_place_mut();
+        assert_eq!((uninit.size(), uninit.align()), (10, 2));
+
+        // Trait objects only know their layout through the allocation.
+        let place = BoxMem::<u64>::alloc().unsize(coercion!(dyn Debug));
+        assert_eq!(place.layout(), Layout::new::<u64>());
+
+        let s: &str = "tinit";
+        let uninit = unsafe { uninit::UninitRef::new_unchecked(s) };
+        assert_eq!((uninit.size(), uninit.align()), (5, 1));
+    }
+
+    #[test]
+    fn uninit_slice() {
+        use uninit::UninitMut;
+
+        let mut buf = [MaybeUninit::<u32>::uninit(); 10];
+        let mut uninit = UninitMut::<[u32]>::from(&mut buf[..]);
+        assert_eq!(uninit.len(), 10);
+        assert!(uninit.get_mut(10).is_none());
+
+        let (mut left, mut right) = uninit.split_at_mut(4);
+        for (i, mut elem) in left.iter_mut().enumerate() {
+            elem.write(i as u32);
+        }
+        for (i, mut chunk) in right.chunks_mut(4).enumerate() {
+            assert_eq!(chunk.len(), [4, 2][i]);
+            for mut elem in chunk.iter_mut() {
+                elem.write(10 + i as u32);
+            }
+        }
+        uninit.get_mut(9).unwrap().write(99);
+        assert_eq!(unsafe { uninit.get(0).unwrap().read() }, 0);
+
+        let buf: &mut [MaybeUninit<u32>; 10] = UninitMut::from(&mut buf).into();
+        let values = buf.map(|v| unsafe { v.assume_init() });
+        assert_eq!(values, [0, 1, 2, 3, 10, 10, 10, 10, 11, 99]);
+    }
+
+    #[test]
+    fn borrowed_slice_slot() {
+        let mut buf = [const { MaybeUninit::<String>::uninit() }; 8];
+        let mut slice = Slice::new(buf[..3].into_place());
+        slice.push("a".into());
+        slice.push("b".into());
+        slice.push("c".into());
+        let own: Own<'_, [String]> = slice.assert_full();
+        assert_eq!(own.concat(), "abc");
+        drop(own);
+
+        let mut storage = [MaybeUninit::uninit(); 8];
+        let squares: Own<'_, [u32; 8]> = emplace!((&mut storage).into_place() => out {
+            let mut slice = Slice::new(out);
+            while !slice.is_full() {
+                slice.push((slice.len() * slice.len()) as u32);
+            }
+            slice.assert_full()
+        });
+        assert_eq!(squares[7], 49);
+
+        let own = (&mut buf).set(core::array::from_fn(|i| i.to_string()));
+        assert_eq!(own[7], "7");
+    }
+
+    #[test]
+    fn const_init() {
+        use uninit::UninitMut;
+
+        const fn squares<const N: usize>(slot: &mut MaybeUninit<[u64; N]>) -> Own<'_, [u64; N]> {
+            let ptr: *mut [u64] = slot.as_mut_ptr();
+            // SAFETY: the pointer comes from a live `&mut`.
+            let mut uninit = unsafe { UninitMut::new_unchecked(ptr) };
+            let elems = uninit.as_uninit_slice_mut();
+            let mut i = 0;
+            while i < elems.len() {
+                elems[i].write((i * i) as u64);
+                i += 1;
+            }
+            // SAFETY: all elements were written above.
+            unsafe { Init::new_unchecked(Mem::new(UninitMut::new_unchecked(slot.as_mut_ptr()))) }
+        }
+
+        const SQUARES: [u64; 16] = {
+            let mut slot = stack_slot();
+            let own = squares(&mut slot);
+            unsafe { Init::forget(own).into_raw().read() }
+        };
+        assert_eq!(SQUARES[15], 225);
+
+        static PAIR: (u8, char) = {
+            let mut slot = stack_slot();
+            let own = Own::new_in(&mut slot, (7, 'x'));
+            unsafe { Init::forget(own).into_raw().read() }
+        };
+        assert_eq!(PAIR, (7, 'x'));
+
+        const LEN: usize = {
+            let mut slot = stack_slot::<[u8; 4]>();
+            let mem = Mem::new(unsafe { UninitMut::new_unchecked(slot.as_mut_ptr()) });
+            let slice = Slice::new(mem);
+            let len = if slice.is_empty() { slice.len() } else { usize::MAX };
+            slice.forget();
+            len
+        };
+        assert_eq!(LEN, 0);
+    }
+
     #[test]
     fn drop_own() {
         struct SetOnDrop<'r>(&'r mut bool);
@@ -94,4 +573,484 @@ mod tests {
 
         assert!(dropped);
     }
+
+    #[test]
+    fn slice_vec_api() {
+        let slot = &mut stack_slot::<[u32; 16]>();
+        let mut slice = Slice::new(Mem::new(slot.into()));
+
+        slice.extend([5, 1, 1, 2, 3, 3, 3, 4]);
+        slice.dedup();
+        assert_eq!(&*slice, [5, 1, 2, 3, 4]);
+
+        slice.insert(0, 7);
+        assert_eq!(slice.remove(1), 5);
+        assert_eq!(slice.swap_remove(0), 7);
+        assert_eq!(&*slice, [4, 1, 2, 3]);
+
+        slice.retain(|v| v % 2 == 0);
+        slice.extend_from_slice(&[6, 8, 10, 12]);
+        let drained: Vec<u32> = slice.drain(1..3).collect();
+        assert_eq!(drained, [2, 6]);
+        assert_eq!(&*slice, [4, 8, 10, 12]);
+
+        let other = &mut stack_slot::<[u32; 4]>();
+        let tail = slice.split_off(2, Mem::new(other.into()));
+        slice.truncate(1);
+        assert_eq!(&*slice, [4]);
+        assert_eq!(tail.into_iter().rev().collect::<Vec<_>>(), [12, 10]);
+    }
+
+    #[test]
+    fn slice_retain_panic() {
+        let slot = &mut stack_slot::<[String; 6]>();
+        let mut slice = Slice::new(Mem::new(slot.into()));
+        slice.extend(["a", "b", "c", "d", "e", "f"].map(String::from));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            slice.retain(|s| match s.as_str() {
+                "b" => false,
+                "d" => std::panic!("cannot retain d"),
+                _ => true,
+            });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(&*slice, ["a", "c", "d", "e", "f"]);
+    }
+
+    #[test]
+    fn init_from_fn() {
+        // 8MiB, larger than the default test thread stack.
+        let big: Box<[u64; 1 << 20]> = Box::emplace().init_from_fn(|i| i as u64 * 2);
+        assert_eq!(big[12345], 24690);
+
+        let names = Box::<[String]>::emplace_slice(3).init_from_fn(|i| i.to_string());
+        assert_eq!(&*names, ["0", "1", "2"]);
+
+        let drops = DropCounter::default();
+        let result = std::panic::catch_unwind(|| {
+            let slot = &mut stack_slot::<[Tracked; 8]>();
+            let own = slot.init_from_fn(|i| match i {
+                5 => std::panic!("cannot build element 5"),
+                _ => drops.track(),
+            });
+            drop(own);
+        });
+
+        assert!(result.is_err());
+        assert_eq!(drops.count(), 5);
+    }
+
+    #[test]
+    fn init_from_iter() {
+        use slice::CollectError;
+
+        let words = ["in", "place", "init"];
+        let boxed = Box::<[String]>::emplace_from_iter(words.iter().map(|s| s.to_string()));
+        assert_eq!(&*boxed.unwrap(), words);
+
+        let slot = &mut stack_slot::<[u8; 4]>();
+        assert_eq!(*slot.init_from_iter(1..=4).unwrap(), [1, 2, 3, 4]);
+
+        let slot = &mut stack_slot::<[u8; 4]>();
+        let err = slot.init_from_iter(1..=3).err();
+        assert_eq!(err, Some(CollectError::TooShort { len: 3, capacity: 4 }));
+
+        let err = Box::<[u8; 4]>::emplace().init_from_iter(1..).err();
+        assert_eq!(err, Some(CollectError::TooLong { capacity: 4 }));
+
+        let parsed = Box::<[u32; 3]>::emplace().try_init_from_iter(["1", "2", "3"].map(str::parse));
+        assert_eq!(*parsed.unwrap(), [1, 2, 3]);
+
+        let err = Box::<[u32; 3]>::emplace().try_init_from_iter(["1", "x", "3"].map(str::parse));
+        assert!(matches!(err, Err(CollectError::Iter(_))));
+        assert_eq!(err.unwrap_err().to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn zeroed() {
+        use core::cell::Cell;
+        use core::ptr::NonNull;
+        use mem::{AllocError, Allocator, BoxMem};
+        use std::alloc::{GlobalAlloc, Layout, System};
+
+        struct Stats {
+            hits: u64,
+            table: [Option<core::num::NonZero<u32>>; 256],
+            last: Option<&'static Stats>,
+        }
+
+        derive_zeroable!(Stats { hits, table, last });
+
+        let slot = &mut stack_slot::<Stats>();
+        let stats = slot.zeroed();
+        assert_eq!(stats.hits, 0);
+        assert!(stats.table.iter().all(Option::is_none));
+        assert!(stats.last.is_none());
+
+        // 64MiB, backed by fresh zeroed pages.
+        let big = Box::<[u64; 1 << 23]>::emplace_zeroed();
+        assert_eq!(big[12345], 0);
+
+        let slice = Box::<[(u8, f32)]>::emplace_slice_zeroed(100);
+        assert_eq!(slice[99], (0, 0.0));
+
+        #[derive(Default)]
+        struct ZeroedOnly {
+            zeroed: Cell<usize>,
+        }
+
+        unsafe impl Allocator for ZeroedOnly {
+            fn allocate(&self, _: Layout) -> Result<NonNull<u8>, AllocError> {
+                Err(AllocError)
+            }
+
+            fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                self.zeroed.set(self.zeroed.get() + 1);
+                NonNull::new(unsafe { System.alloc_zeroed(layout) }).ok_or(AllocError)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                unsafe { System.dealloc(ptr.as_ptr(), layout) }
+            }
+        }
+
+        let alloc = ZeroedOnly::default();
+        assert!(BoxMem::<u32, _>::try_alloc_in(&alloc).is_err());
+        let boxed = unsafe { BoxMem::<[u32; 4], _>::alloc_zeroed_in(&alloc).assume_init() };
+        assert_eq!(*boxed, [0; 4]);
+        assert_eq!(alloc.zeroed.get(), 1);
+    }
+
+    #[test]
+    fn try_emplace() {
+        fn parse_all(input: [&str; 4]) -> Result<Box<[u32; 4]>, core::num::ParseIntError> {
+            try_emplace!(Box::<[u32; 4]>::emplace() => out {
+                let mut slice = Slice::new(out);
+                for s in input {
+                    slice.push(s.parse()?);
+                }
+                Ok(slice.assert_full())
+            })
+        }
+
+        assert_eq!(*parse_all(["1", "2", "3", "4"]).unwrap(), [1, 2, 3, 4]);
+        assert!(parse_all(["1", "2", "x", "4"]).is_err());
+
+        fn first_even(inputs: &[u32]) -> Option<Box<u32>> {
+            for &n in inputs {
+                let boxed: Result<Box<u32>, ()> = try_emplace!(Box::<u32>::emplace() => out {
+                    if n % 2 != 0 {
+                        continue;
+                    }
+                    Ok(out.set(n))
+                });
+                return boxed.ok();
+            }
+            None
+        }
+
+        assert_eq!(first_even(&[1, 3, 4, 6]).as_deref(), Some(&4));
+        assert_eq!(first_even(&[1, 3]), None);
+
+        let err = Box::<u32>::emplace().try_with(|out| {
+            let filled = out.set(1);
+            if *filled == 1 {
+                return Err("one");
+            }
+            Ok(filled)
+        });
+        assert_eq!(err, Err("one"));
+    }
+
+    #[test]
+    fn clone_into() {
+        use clone::CloneInto;
+
+        project! {
+            #[project = DocFields]
+            struct Doc {
+                title: String,
+                pages: [[u8; 4096]; 64],
+                refs: (u32, Box<[u16]>),
+            }
+        }
+
+        derive_clone_into!(Doc { title, pages, refs });
+
+        let doc = emplace!(Box::<Doc>::emplace() => out {
+            let mut doc = Projection::new(out);
+            let fields = doc.fields();
+            fields.title.set("tinit".into());
+            fields.pages.zeroed()[3][7] = 42;
+            fields.refs.set((7, vec![1, 2, 3].into_boxed_slice()));
+            doc.finish()
+        });
+
+        let copy: Box<Doc> = (*doc).clone_into_place(Box::emplace());
+        assert_eq!(copy.title, "tinit");
+        assert_eq!(copy.pages[3][7], 42);
+        assert_eq!(copy.refs.0, 7);
+        assert_eq!(*copy.refs.1, [1, 2, 3]);
+
+        let slot = &mut stack_slot::<[String; 2]>();
+        let strings = Own::new_in(slot, [String::from("a"), String::from("b")]);
+        let slot = &mut stack_slot::<[String; 2]>();
+        let copy = strings.clone_into_place(Mem::new(slot.into()));
+        assert_eq!(*copy, ["a", "b"]);
+
+        let boxed = Box::<[u64]>::emplace_slice(3).init_from_fn(|i| i as u64 * 2);
+        assert_eq!(*(*boxed).clone_into_place(Box::emplace_slice(3)), [0, 2, 4]);
+
+        let short = std::panic::catch_unwind(|| {
+            drop((*boxed).clone_into_place(Box::emplace_slice(2)));
+        });
+        assert!(short.is_err());
+
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        derive_clone_into!(Point);
+
+        let points = [Point { x: 1, y: 2 }, Point { x: -3, y: 4 }];
+        let copy: Box<[Point; 2]> = points.clone_into_place(Box::emplace());
+        assert_eq!(*copy, points);
+    }
+
+    #[test]
+    fn default_in_place() {
+        #[derive(Default)]
+        struct Config {
+            name: String,
+            retries: u32,
+        }
+
+        derive_default_in_place!(Config);
+
+        project! {
+            #[project = HugeStateFields]
+            struct HugeState {
+                config: Config,
+                history: [(u64, Option<Box<str>>); 1 << 16],
+                scratch: Box<[u8; 1 << 20]>,
+            }
+        }
+
+        derive_default_in_place!(HugeState { config, history, scratch });
+
+        let state = Box::<HugeState>::emplace_default();
+        assert_eq!(state.config.name, "");
+        assert_eq!(state.config.retries, 0);
+        assert!(state.history.iter().all(|(n, s)| *n == 0 && s.is_none()));
+        assert_eq!(state.scratch[12345], 0);
+
+        let slot = &mut stack_slot::<[Config; 4]>();
+        let configs = Own::default_in(slot);
+        assert!(configs.iter().all(|c| c.name.is_empty()));
+    }
+
+    #[test]
+    fn init_traits() {
+        use core::fmt::Write;
+        use mem::{BoxMem, Global};
+        use std::collections::BTreeSet;
+        use std::format;
+
+        let boxed = |s: &str| Init::<BoxMem<String, Global>>::new(BoxMem::alloc(), s.into());
+        let set: BTreeSet<_> = ["b", "a", "c"].into_iter().map(boxed).collect();
+        assert!(set.contains(&String::from("a")));
+        assert_eq!(format!("{:?}", set), r#"{"a", "b", "c"}"#);
+
+        let slot = &mut stack_slot();
+        let mut own = Own::new_in(slot, String::from("x"));
+        write!(own, "{}", 42).unwrap();
+        assert_eq!(format!("{own}"), "x42");
+        assert_eq!(own, boxed("x42"));
+        assert!(own < boxed("y"));
+        assert_eq!(own.as_ref(), "x42");
+
+        let slot = &mut stack_slot();
+        let iter = Own::new_in(slot, 0..10);
+        assert_eq!(iter.rev().step_by(3).collect::<Vec<_>>(), [9, 6, 3, 0]);
+
+        let slot = &mut stack_slot();
+        let fut = Own::new_in(slot, core::future::ready(7));
+        assert_eq!(block_on(fut), 7);
+
+        pin_slot!(slot);
+        let fut = slot.pin_with(|out| out.set(async { 8 }));
+        assert_eq!(block_on(fut), 8);
+
+        let slot = &mut stack_slot();
+        let err = Own::new_in(slot, slice::CollectError::<core::convert::Infallible>::TooLong { capacity: 2 });
+        let err: &dyn core::error::Error = &err;
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn emplace_rc() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let rc: Rc<[u8; 4]> = emplace!(Rc::emplace() => out { out.set(*b"tini") });
+        assert_eq!(&*rc, b"tini");
+
+        let arc = emplace!(Arc::<[u16; 32]>::emplace() => out {
+            let mut slice = Slice::new(out);
+            while !slice.is_full() {
+                slice.push(slice.len() as u16);
+            }
+            slice.assert_full()
+        });
+        assert_eq!(arc[31], 31);
+
+        let rc = Rc::<String>::new_uninit().set("tinit".into());
+        assert_eq!(*rc, "tinit");
+    }
+
+    #[test]
+    fn emplace_vec() {
+        use vec::VecExt;
+
+        let mut vec: Vec<[u32; 256]> = Vec::new();
+        for i in 0..4 {
+            emplace!(vec.emplace() => out {
+                let mut slice = Slice::new(out);
+                while !slice.is_full() {
+                    slice.push(i);
+                }
+                slice.assert_full()
+            });
+        }
+        vec.emplace_at(1).set([9; 256]);
+        drop(vec.emplace_at(3));
+
+        let firsts: Vec<u32> = vec.iter().map(|a| a[0]).collect();
+        assert_eq!(firsts, [0, 9, 1, 2, 3]);
+    }
+
+    project! {
+        #[project = PairFields]
+        struct Pair {
+            small: u32,
+            large: [u64; 64],
+        }
+    }
+
+    #[test]
+    fn project_fields() {
+        let pair = emplace!(Box::<Pair>::emplace() => out {
+            let mut pair = Projection::new(out);
+            let fields = pair.fields();
+            fields.small.set(42);
+            emplace!(fields.large => out {
+                let mut slice = Slice::new(out);
+                while !slice.is_full() {
+                    let i = slice.len() as u64;
+                    slice.push(i * i);
+                }
+                slice.assert_full()
+            });
+            pair.finish()
+        });
+
+        assert_eq!(pair.small, 42);
+        assert_eq!(pair.large[10], 100);
+    }
+
+    #[test]
+    fn drop_partial_projection() {
+        project! {
+            #[project = TripleFields]
+            struct Triple {
+                a: Tracked,
+                b: Tracked,
+                c: Tracked,
+            }
+        }
+
+        let drops = DropCounter::default();
+        let slot = &mut stack_slot::<Triple>();
+        let mut triple = Projection::new(Mem::new(slot.into()));
+        let fields = triple.fields();
+        fields.a.set(drops.track());
+        fields.c.set(drops.track());
+        assert!(!triple.is_init());
+        drop(triple);
+
+        assert_eq!(drops.count(), 2);
+    }
+
+    #[test]
+    fn reproject_initialized_field() {
+        project! {
+            #[project = SingleFields]
+            struct Single {
+                a: Tracked,
+            }
+        }
+
+        let drops = DropCounter::default();
+
+        // The first value is leaked, and the second one is dropped once by `try_with`.
+        let slot = &mut stack_slot::<Single>();
+        let mut single = Projection::new(Mem::new(slot.into()));
+        single.fields().a.set(drops.track());
+        let err = single.fields().a.try_with(|out| {
+            let _tracked = out.set(drops.track());
+            Err(())
+        });
+        assert!(err.is_err());
+        drop(single);
+        assert_eq!(drops.count(), 1);
+
+        let slot = &mut stack_slot::<Single>();
+        let mut single = Projection::new(Mem::new(slot.into()));
+        single.fields().a.set(drops.track());
+        let err = single.fields().a.try_with(|out| {
+            let _place = Init::drop(out.set(drops.track()));
+            Err(())
+        });
+        assert!(err.is_err());
+        assert!(!single.is_init());
+        drop(single);
+        assert_eq!(drops.count(), 2);
+    }
+
+    #[test]
+    fn drop_projection_panic() {
+        struct PanicOnDrop {
+            _tracked: Tracked,
+        }
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if !std::thread::panicking() {
+                    std::panic!("field destructor panicked");
+                }
+            }
+        }
+
+        project! {
+            #[project = FallibleFields]
+            struct Fallible {
+                a: PanicOnDrop,
+            }
+        }
+
+        let drops = DropCounter::default();
+        let result = std::panic::catch_unwind(|| {
+            let slot = &mut stack_slot::<Fallible>();
+            let mut fallible = Projection::new(Mem::new(slot.into()));
+            fallible.fields().a.set(PanicOnDrop { _tracked: drops.track() });
+            fallible.drop();
+        });
+
+        assert!(result.is_err());
+        assert_eq!(drops.count(), 1);
+    }
 }
@@ -1,5 +1,9 @@
+pub use allocator::{AllocError, Allocator, BoxAlloc, Global};
 pub use base::Mem;
-pub use heap::BoxMem;
+#[cfg(target_has_atomic = "8")]
+pub use cell::StaticCell;
+pub use heap::{BoxMem, Boxed};
+pub use rc::{ArcMem, RcMem};
 pub use scoped::{Scope, ScopedMem};
 
 macro_rules! impl_place_deref {
@@ -16,7 +20,11 @@ macro_rules! impl_place_deref {
     };
 }
 
+mod allocator;
 mod base;
+#[cfg(target_has_atomic = "8")]
+mod cell;
 mod heap;
+mod rc;
 mod scoped;
 
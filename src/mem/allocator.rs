@@ -0,0 +1,155 @@
+use core::fmt;
+use core::ops::Deref;
+use core::ptr::NonNull;
+
+use alloc::alloc::Layout;
+use alloc::boxed::Box;
+
+use crate::init::Init;
+use crate::place::Place;
+use super::BoxMem;
+
+/// The error returned when an allocation fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl core::error::Error for AllocError {}
+
+/// A custom memory allocator, usable on stable Rust.
+///
+/// Places allocated through an `Allocator` finish into a [`Boxed<T, A>`](super::Boxed);
+/// the global allocator isn't an `Allocator`, and is represented by [`Global`] instead.
+///
+/// # Safety
+///
+/// Memory blocks returned by `allocate` must be valid for the requested layout, and stay
+/// valid until they are passed to `deallocate` (which may happen through another copy
+/// of the allocator).
+pub unsafe trait Allocator {
+    /// Allocates a memory block; `layout` is never zero-sized.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// Like `allocate`, but the returned memory block is filled with zeroes.
+    #[inline]
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let ptr = self.allocate(layout)?;
+        unsafe { ptr.write_bytes(0, layout.size()) };
+        Ok(ptr)
+    }
+
+    /// Deallocates a memory block.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by `allocate` or `allocate_zeroed` on this allocator,
+    /// with the same `layout`, and must not be used afterwards.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+}
+
+unsafe impl<A: Allocator + ?Sized> Allocator for &A {
+    #[inline(always)]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        (**self).allocate(layout)
+    }
+
+    #[inline(always)]
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        (**self).allocate_zeroed(layout)
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { (**self).deallocate(ptr, layout) }
+    }
+}
+
+/// The global allocator, whose places finish into a [`Box<T>`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Global;
+
+/// An allocator backing a [`BoxMem`]: either [`Global`] or a custom [`Allocator`].
+pub trait BoxAlloc: Sized + sealed::Sealed {
+    /// The owning pointer produced by [`BoxMem<T, Self>`](BoxMem).
+    type Boxed<T: ?Sized>: Deref<Target = T>;
+
+    #[doc(hidden)]
+    fn alloc_raw(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    #[doc(hidden)]
+    fn alloc_raw_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// # Safety
+    ///
+    /// See [`Allocator::deallocate`].
+    #[doc(hidden)]
+    unsafe fn dealloc_raw(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// # Safety
+    ///
+    /// `mem` must be initialized.
+    #[doc(hidden)]
+    unsafe fn finish<T: ?Sized>(mem: BoxMem<T, Self>) -> Self::Boxed<T>;
+}
+
+impl BoxAlloc for Global {
+    type Boxed<T: ?Sized> = Box<T>;
+
+    #[inline(always)]
+    fn alloc_raw(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        NonNull::new(unsafe { alloc::alloc::alloc(layout) }).ok_or(AllocError)
+    }
+
+    #[inline(always)]
+    fn alloc_raw_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        NonNull::new(unsafe { alloc::alloc::alloc_zeroed(layout) }).ok_or(AllocError)
+    }
+
+    #[inline(always)]
+    unsafe fn dealloc_raw(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { alloc::alloc::dealloc(ptr.as_ptr(), layout) }
+    }
+
+    #[inline(always)]
+    unsafe fn finish<T: ?Sized>(mem: BoxMem<T, Self>) -> Self::Boxed<T> {
+        // The allocation is now owned by the box.
+        unsafe { Box::from_raw(mem.leak().as_mut_ptr()) }
+    }
+}
+
+impl<A: Allocator> BoxAlloc for A {
+    type Boxed<T: ?Sized> = Init<BoxMem<T, A>>;
+
+    #[inline(always)]
+    fn alloc_raw(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.allocate(layout)
+    }
+
+    #[inline(always)]
+    fn alloc_raw_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.allocate_zeroed(layout)
+    }
+
+    #[inline(always)]
+    unsafe fn dealloc_raw(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.deallocate(ptr, layout) }
+    }
+
+    #[inline(always)]
+    unsafe fn finish<T: ?Sized>(mem: BoxMem<T, Self>) -> Self::Boxed<T> {
+        unsafe { Init::new_unchecked(mem) }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Global {}
+
+    impl<A: super::Allocator> Sealed for A {}
+}
@@ -4,8 +4,9 @@ use core::panic::{RefUnwindSafe, UnwindSafe};
 use core::ptr::NonNull;
 
 use crate::init::Init;
-use crate::place::{Place, Slot};
+use crate::place::{CastPlace, Place, Slot};
 use crate::uninit::{UninitMut, UninitRef};
+use crate::unsize::{Coercion, UnsizePlace};
 
 
 // TODO: document
@@ -19,16 +20,18 @@ pub struct Mem<'scope, T: ?Sized> {
 
 impl<'s, T: ?Sized> Mem<'s, T> {
     #[inline(always)]
-    pub fn new(mut uninit: UninitMut<'s, T>) -> Self {
+    pub const fn new(mut uninit: UninitMut<'s, T>) -> Self {
         Self {
             ptr: uninit.as_non_null(),
             _marker: PhantomData,
         }
     }
 
-    // SAFETY: ptr must be live and unaliased during `'s`.
+    /// # Safety
+    ///
+    /// `uninit` must be live and unaliased during `'s`.
     #[inline(always)]
-    pub unsafe fn from_raw(uninit: *mut T) -> Self {
+    pub const unsafe fn from_raw(uninit: *mut T) -> Self {
         Self {
             ptr: unsafe { NonNull::new_unchecked(uninit) },
             _marker: PhantomData,
@@ -36,10 +39,34 @@ impl<'s, T: ?Sized> Mem<'s, T> {
     }
 
     #[inline(always)]
-    pub fn into_raw(self) -> *mut T {
+    pub const fn into_raw(self) -> *mut T {
         self.ptr.as_ptr()
     }
 
+    #[inline(always)]
+    pub fn unsize<U: ?Sized>(self, coercion: Coercion<T, U>) -> Mem<'s, U> {
+        unsafe { Mem::from_raw(coercion.coerce(self.into_raw())) }
+    }
+}
+
+impl<'s, T> Mem<'s, T> {
+    /// Reinterprets the memory as a place of `U`.
+    ///
+    /// Fails to compile if `U` is larger or more aligned than `T`:
+    ///
+    /// ```compile_fail
+    /// let slot = &mut tinit::stack_slot::<[u8; 4]>();
+    /// let mem = tinit::Mem::new(slot.into()).cast::<u32>();
+    /// ```
+    #[inline(always)]
+    pub const fn cast<U>(self) -> Mem<'s, U> {
+        const {
+            assert!(size_of::<U>() <= size_of::<T>(), "target type is too large");
+            assert!(align_of::<U>() <= align_of::<T>(), "target type is over-aligned");
+        }
+        // SAFETY: the memory is large and aligned enough for `U`.
+        unsafe { Mem::from_raw(self.into_raw().cast()) }
+    }
 }
 
 unsafe impl<'s, T: ?Sized> Place for Mem<'s, T> {
@@ -62,6 +89,24 @@ unsafe impl<'s, T: ?Sized> Place for Mem<'s, T> {
     }
 }
 
+unsafe impl<'s, T: ?Sized, U: ?Sized> UnsizePlace<U> for Mem<'s, T> {
+    type Unsized = Mem<'s, U>;
+
+    #[inline(always)]
+    fn unsize_place(self, coercion: Coercion<T, U>) -> Self::Unsized {
+        self.unsize(coercion)
+    }
+}
+
+unsafe impl<'s, T, U> CastPlace<U> for Mem<'s, T> {
+    type Cast = Mem<'s, U>;
+
+    #[inline(always)]
+    fn cast_place(self) -> Self::Cast {
+        self.cast()
+    }
+}
+
 // Unconditionally implement a bunch of auto-traits, as we
 // don't care about the actual type inside.
 unsafe impl<'s, T: ?Sized> Send for Mem<'s, T> {}
@@ -79,7 +124,7 @@ impl<'s, T> Slot<Init<Mem<'s, T>>> for &'s mut MaybeUninit<T> {
     }
 }
 
-impl<'s, T> Slot<Init<Mem<'s, T>>> for UninitMut<'s, T> {
+impl<'s, T: ?Sized> Slot<Init<Mem<'s, T>>> for UninitMut<'s, T> {
     type Place = Mem<'s, T>;
 
     #[inline(always)]
@@ -88,4 +133,20 @@ impl<'s, T> Slot<Init<Mem<'s, T>>> for UninitMut<'s, T> {
     }
 }
 
-// TODO: impl Slot for &'s mut [MU<T>]
+impl<'s, T> Slot<Init<Mem<'s, [T]>>> for &'s mut [MaybeUninit<T>] {
+    type Place = Mem<'s, [T]>;
+
+    #[inline(always)]
+    fn into_place(self) -> Self::Place {
+        Mem::new(UninitMut::from(self))
+    }
+}
+
+impl<'s, T, const N: usize> Slot<Init<Mem<'s, [T; N]>>> for &'s mut [MaybeUninit<T>; N] {
+    type Place = Mem<'s, [T; N]>;
+
+    #[inline(always)]
+    fn into_place(self) -> Self::Place {
+        Mem::new(UninitMut::from(self))
+    }
+}
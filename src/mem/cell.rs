@@ -0,0 +1,85 @@
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use super::Mem;
+
+/// Static storage for a value initialized at runtime.
+///
+/// The place of the cell is handed out at most once, as a [`Mem<'static, T>`]; it can
+/// then be initialized like any other place, and finished into an
+/// [`Own<'static, T>`](crate::Own), or a `&'static mut T` with [`Init::leak`](crate::Init::leak).
+///
+/// ```
+/// use tinit::mem::StaticCell;
+/// use tinit::{Init, Slot};
+///
+/// static BUFFER: StaticCell<[u8; 4096]> = StaticCell::new();
+///
+/// let buffer: &'static mut [u8; 4096] = Init::leak(BUFFER.emplace().zeroed());
+/// buffer[0] = 1;
+/// assert!(BUFFER.take_place().is_none());
+/// ```
+pub struct StaticCell<T> {
+    taken: AtomicBool,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: the value is only ever accessed through the unique place handed out, which
+// may be sent to another thread.
+unsafe impl<T: Send> Sync for StaticCell<T> {}
+
+impl<T> StaticCell<T> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            taken: AtomicBool::new(false),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns the place of the cell, or `None` if it was already taken.
+    #[inline]
+    pub fn take_place(&'static self) -> Option<Mem<'static, T>> {
+        if self.taken.swap(true, Ordering::Acquire) {
+            None
+        } else {
+            // SAFETY: the flag ensures that the place is unique, and the cell lives forever.
+            Some(unsafe { Mem::from_raw(self.value.get().cast()) })
+        }
+    }
+
+    /// Like [`take_place`](Self::take_place), but panics if the place was already taken.
+    #[inline]
+    pub fn emplace(&'static self) -> Mem<'static, T> {
+        match self.take_place() {
+            Some(place) => place,
+            None => panic_taken(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_taken(&self) -> bool {
+        self.taken.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Default for StaticCell<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for StaticCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticCell").field("taken", &self.is_taken()).finish_non_exhaustive()
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn panic_taken() -> ! {
+    panic!("static cell was already taken")
+}
@@ -1,59 +1,300 @@
-use core::mem::MaybeUninit;
-use core::ptr::NonNull;
-use core::{mem, slice};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::pin::Pin;
+use core::ptr::{self, NonNull};
+use core::slice;
 
 use alloc::alloc::Layout;
 use alloc::boxed::Box;
 
-use crate::place::{Emplace, Place, Slot};
-use super::Mem;
+use crate::init::Init;
+use crate::pin::PinPlace;
+use crate::place::{CastPlace, Emplace, EmplaceSlice, Place, Slot, TryEmplace};
+use crate::unsize::{Coercion, UnsizePlace};
+use crate::zeroable::Zeroable;
+use super::{AllocError, BoxAlloc, Global, Mem};
 
-pub struct BoxMem<T: ?Sized>(Mem<'static, T>);
+/// An owned heap allocation, not yet initialized.
+///
+/// Finishes into a [`Box<T>`] for the [`Global`] allocator, and into a [`Boxed<T, A>`]
+/// for custom [`Allocator`](super::Allocator)s.
+// The layout is the one the memory was allocated with: it can't be computed from the
+// pointer once the place is unsized to a trait object.
+pub struct BoxMem<T: ?Sized, A: BoxAlloc = Global>(Mem<'static, T>, A, Layout);
+
+/// A value allocated in a custom [`Allocator`](super::Allocator).
+pub type Boxed<T, A> = Init<BoxMem<T, A>>;
 
 impl<T> BoxMem<T> {
     #[inline]
     pub fn alloc() -> Self {
-        unsafe {
-            let raw = if mem::size_of::<T>() == 0 {
-                NonNull::<T>::dangling().as_ptr()
-            } else {
-                let layout = Layout::new::<T>();
-                alloc::alloc::alloc(layout) as *mut T
-            };
-            Self(Mem::from_raw(raw))
-        }
+        Self::alloc_in(Global)
+    }
+
+    #[inline]
+    pub fn try_alloc() -> Result<Self, AllocError> {
+        Self::try_alloc_in(Global)
+    }
+
+    /// Like [`alloc`](Self::alloc), but the memory is filled with zeroes.
+    #[inline]
+    pub fn alloc_zeroed() -> Self {
+        Self::alloc_zeroed_in(Global)
+    }
+
+    #[inline]
+    pub fn try_alloc_zeroed() -> Result<Self, AllocError> {
+        Self::try_alloc_zeroed_in(Global)
     }
 }
 
 impl<T> BoxMem<[T]> {
+    /// # Panics
+    ///
+    /// Panics if the slice is larger than `isize::MAX` bytes.
     #[inline]
     pub fn alloc_slice(len: usize) -> Self {
-        let layout = Layout::from_size_align(
-            mem::size_of::<T>().saturating_mul(len),
-            mem::align_of::<T>(),
-        ).unwrap_or_else(|_| panic!("slice capacity overflow"));
+        Self::alloc_slice_in(len, Global)
+    }
+
+    #[inline]
+    pub fn try_alloc_slice(len: usize) -> Result<Self, AllocError> {
+        Self::try_alloc_slice_in(len, Global)
+    }
+
+    /// Like [`alloc_slice`](Self::alloc_slice), but the memory is filled with zeroes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is larger than `isize::MAX` bytes.
+    #[inline]
+    pub fn alloc_slice_zeroed(len: usize) -> Self {
+        Self::alloc_slice_zeroed_in(len, Global)
+    }
+
+    #[inline]
+    pub fn try_alloc_slice_zeroed(len: usize) -> Result<Self, AllocError> {
+        Self::try_alloc_slice_zeroed_in(len, Global)
+    }
+}
+
+impl<T, A: BoxAlloc> BoxMem<T, A> {
+    #[inline]
+    pub fn alloc_in(alloc: A) -> Self {
+        Self::try_alloc_in(alloc).unwrap_or_else(|_| handle_alloc_error(Layout::new::<T>()))
+    }
+
+    #[inline]
+    pub fn try_alloc_in(alloc: A) -> Result<Self, AllocError> {
+        Self::try_alloc_with(alloc, false)
+    }
+
+    #[inline]
+    pub fn alloc_zeroed_in(alloc: A) -> Self {
+        Self::try_alloc_zeroed_in(alloc).unwrap_or_else(|_| handle_alloc_error(Layout::new::<T>()))
+    }
 
+    #[inline]
+    pub fn try_alloc_zeroed_in(alloc: A) -> Result<Self, AllocError> {
+        Self::try_alloc_with(alloc, true)
+    }
+
+    /// Reinterprets the allocation as a place of `U`.
+    ///
+    /// Fails to compile if `U` doesn't have the same size and alignment as `T`, as the
+    /// allocation must be freed with the same layout.
+    #[inline(always)]
+    pub fn cast<U>(self) -> BoxMem<U, A> {
+        const {
+            assert!(size_of::<U>() == size_of::<T>(), "types have different sizes");
+            assert!(align_of::<U>() == align_of::<T>(), "types have different alignments");
+        }
+        // Disable the drop impl.
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` isn't accessed nor dropped after these lines.
+        let (mem, alloc) = unsafe { (ptr::read(&this.0), ptr::read(&this.1)) };
+        BoxMem(mem.cast(), alloc, this.2)
+    }
+
+    #[inline(always)]
+    fn try_alloc_with(alloc: A, zeroed: bool) -> Result<Self, AllocError> {
+        let layout = Layout::new::<T>();
+        let raw = alloc_layout(&alloc, layout, zeroed)?.cast::<T>();
+        unsafe { Ok(Self(Mem::from_raw(raw.as_ptr()), alloc, layout)) }
+    }
+}
+
+impl<T, A: BoxAlloc> BoxMem<[T], A> {
+    /// # Panics
+    ///
+    /// Panics if the slice is larger than `isize::MAX` bytes.
+    #[inline]
+    pub fn alloc_slice_in(len: usize, alloc: A) -> Self {
+        let layout = array::<T>(len);
+        Self::try_alloc_slice_with(len, layout, alloc, false)
+            .unwrap_or_else(|_| handle_alloc_error(layout))
+    }
+
+    #[inline]
+    pub fn try_alloc_slice_in(len: usize, alloc: A) -> Result<Self, AllocError> {
+        let layout = Layout::array::<T>(len).map_err(|_| AllocError)?;
+        Self::try_alloc_slice_with(len, layout, alloc, false)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the slice is larger than `isize::MAX` bytes.
+    #[inline]
+    pub fn alloc_slice_zeroed_in(len: usize, alloc: A) -> Self {
+        let layout = array::<T>(len);
+        Self::try_alloc_slice_with(len, layout, alloc, true)
+            .unwrap_or_else(|_| handle_alloc_error(layout))
+    }
+
+    #[inline]
+    pub fn try_alloc_slice_zeroed_in(len: usize, alloc: A) -> Result<Self, AllocError> {
+        let layout = Layout::array::<T>(len).map_err(|_| AllocError)?;
+        Self::try_alloc_slice_with(len, layout, alloc, true)
+    }
+
+    #[inline(always)]
+    fn try_alloc_slice_with(
+        len: usize,
+        layout: Layout,
+        alloc: A,
+        zeroed: bool,
+    ) -> Result<Self, AllocError> {
+        let raw = alloc_layout(&alloc, layout, zeroed)?.cast::<T>();
         unsafe {
-            let raw = if layout.size() == 0 {
-                NonNull::<T>::dangling().as_ptr()
-            } else {
-                alloc::alloc::alloc(layout) as *mut T
-            };
-            let slice = slice::from_raw_parts_mut(raw, len);
-            Self(Mem::from_raw(slice))
+            let slice = slice::from_raw_parts_mut(raw.as_ptr(), len);
+            Ok(Self(Mem::from_raw(slice), alloc, layout))
         }
     }
 }
 
-unsafe impl<T> Place for BoxMem<T> {
+impl<T: ?Sized> BoxMem<T> {
+    /// Drops the value of `boxed`, keeping its allocation for reuse.
+    #[inline]
+    pub fn from_box_drop(boxed: Box<T>) -> Self {
+        Init::drop(Self::from_box(boxed))
+    }
+
+    /// Turns `boxed` back into an initialized place.
+    #[inline(always)]
+    pub fn from_box(boxed: Box<T>) -> Boxed<T, Global> {
+        let layout = Layout::for_value(&*boxed);
+        let ptr = Box::into_raw(boxed);
+        // SAFETY: the box was allocated by the global allocator with the layout of its
+        // value, and contains a valid `T`.
+        unsafe { Init::new_unchecked(Self(Mem::from_raw(ptr), Global, layout)) }
+    }
+}
+
+impl<T> BoxMem<T> {
+    /// Moves the value out of `boxed`, keeping its allocation for reuse.
+    #[inline]
+    pub fn from_box_take(boxed: Box<T>) -> (T, Self) {
+        Init::take_full(Self::from_box(boxed))
+    }
+}
+
+impl<T: ?Sized, A: BoxAlloc> BoxMem<T, A> {
+    #[inline(always)]
+    pub fn allocator(&self) -> &A {
+        &self.1
+    }
+
+    /// The layout the memory was allocated with.
+    #[inline(always)]
+    pub fn layout(&self) -> Layout {
+        self.2
+    }
+
+    #[inline(always)]
+    pub fn unsize<U: ?Sized>(self, coercion: Coercion<T, U>) -> BoxMem<U, A> {
+        // Disable the drop impl.
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` isn't accessed nor dropped after these lines.
+        let (mem, alloc) = unsafe { (ptr::read(&this.0), ptr::read(&this.1)) };
+        BoxMem(mem.unsize(coercion), alloc, this.2)
+    }
+}
+
+#[inline]
+fn alloc_layout<A: BoxAlloc>(
+    alloc: &A,
+    layout: Layout,
+    zeroed: bool,
+) -> Result<NonNull<u8>, AllocError> {
+    if layout.size() == 0 {
+        // Like `NonNull::dangling`, for a runtime alignment.
+        let dangling = ptr::without_provenance_mut(layout.align());
+        // SAFETY: the alignment is never zero.
+        unsafe { Ok(NonNull::new_unchecked(dangling)) }
+    } else if zeroed {
+        alloc.alloc_raw_zeroed(layout)
+    } else {
+        alloc.alloc_raw(layout)
+    }
+}
+
+#[inline]
+fn array<T>(len: usize) -> Layout {
+    Layout::array::<T>(len).unwrap_or_else(|_| panic!("slice capacity overflow"))
+}
+
+#[cold]
+#[inline(never)]
+fn handle_alloc_error(layout: Layout) -> ! {
+    alloc::alloc::handle_alloc_error(layout)
+}
+
+unsafe impl<T: ?Sized, A: BoxAlloc> Place for BoxMem<T, A> {
     type Target = T;
-    type Init = Box<T>;
+    type Init = A::Boxed<T>;
 
     impl_place_deref!(use 0);
 
     #[inline(always)]
     unsafe fn assume_init(self) -> Self::Init {
-        unsafe { Box::from_raw(self.0.into_raw()) }
+        unsafe { A::finish(self) }
+    }
+}
+
+unsafe impl<T, U, A: BoxAlloc> CastPlace<U> for BoxMem<T, A> {
+    type Cast = BoxMem<U, A>;
+
+    #[inline(always)]
+    fn cast_place(self) -> Self::Cast {
+        self.cast()
+    }
+}
+
+unsafe impl<T: ?Sized, A: BoxAlloc> PinPlace for BoxMem<T, A> {
+    type PinInit = Pin<A::Boxed<T>>;
+
+    #[inline(always)]
+    unsafe fn assume_init_pinned(self) -> Self::PinInit {
+        // SAFETY: the allocation is only freed after dropping the value.
+        unsafe { Pin::new_unchecked(self.assume_init()) }
+    }
+}
+
+unsafe impl<T: ?Sized, U: ?Sized, A: BoxAlloc> UnsizePlace<U> for BoxMem<T, A> {
+    type Unsized = BoxMem<U, A>;
+
+    #[inline(always)]
+    fn unsize_place(self, coercion: Coercion<T, U>) -> Self::Unsized {
+        self.unsize(coercion)
+    }
+}
+
+impl<T: ?Sized, A: BoxAlloc> Drop for BoxMem<T, A> {
+    #[inline]
+    fn drop(&mut self) {
+        let uninit = self.0.deref_place();
+        if self.2.size() != 0 {
+            unsafe { self.1.dealloc_raw(uninit.as_non_null().cast(), self.2) }
+        }
     }
 }
 
@@ -64,6 +305,22 @@ impl<T> Emplace for Box<T> {
     fn emplace() -> Self::Place {
         BoxMem::alloc()
     }
+
+    #[inline]
+    fn emplace_zeroed() -> Self
+    where
+        T: Zeroable,
+    {
+        // SAFETY: the memory is zeroed, which is a valid `T`.
+        unsafe { BoxMem::alloc_zeroed().assume_init() }
+    }
+}
+
+impl<T> TryEmplace for Box<T> {
+    #[inline]
+    fn try_emplace() -> Result<Self::Place, AllocError> {
+        BoxMem::try_alloc()
+    }
 }
 
 impl<T> Slot<Box<T>> for Box<MaybeUninit<T>> {
@@ -72,8 +329,40 @@ impl<T> Slot<Box<T>> for Box<MaybeUninit<T>> {
     #[inline(always)]
     fn into_place(self) -> Self::Place {
         let ptr = Box::into_raw(self).cast();
-        unsafe { BoxMem(Mem::from_raw(ptr)) }
+        unsafe { BoxMem(Mem::from_raw(ptr), Global, Layout::new::<T>()) }
     }
 }
 
-// TODO: impls for Box<[T]>
+impl<T> EmplaceSlice for Box<[T]> {
+    type Place = BoxMem<[T]>;
+
+    #[inline]
+    fn emplace_slice(len: usize) -> Self::Place {
+        BoxMem::alloc_slice(len)
+    }
+
+    #[inline]
+    fn try_emplace_slice(len: usize) -> Result<Self::Place, AllocError> {
+        BoxMem::try_alloc_slice(len)
+    }
+
+    #[inline]
+    fn emplace_slice_zeroed(len: usize) -> Self
+    where
+        [T]: Zeroable,
+    {
+        // SAFETY: the memory is zeroed, which is a valid `[T]`.
+        unsafe { BoxMem::alloc_slice_zeroed(len).assume_init() }
+    }
+}
+
+impl<T> Slot<Box<[T]>> for Box<[MaybeUninit<T>]> {
+    type Place = BoxMem<[T]>;
+
+    #[inline(always)]
+    fn into_place(self) -> Self::Place {
+        let layout = Layout::for_value(&*self);
+        let ptr = Box::into_raw(self) as *mut [T];
+        unsafe { BoxMem(Mem::from_raw(ptr), Global, layout) }
+    }
+}
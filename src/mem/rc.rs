@@ -0,0 +1,96 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::pin::Pin;
+
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+
+use crate::pin::PinPlace;
+use crate::place::{Emplace, Place, Slot};
+use super::Mem;
+
+macro_rules! impl_rc_mem {
+    ($(#[$attr:meta])* $name:ident => $rc:ident) => {
+        $(#[$attr])*
+        pub struct $name<T>(Mem<'static, T>);
+
+        impl<T> $name<T> {
+            #[inline]
+            pub fn alloc() -> Self {
+                let raw = $rc::into_raw($rc::<T>::new_uninit());
+                // SAFETY: the allocation is unique, so we can write through the pointer.
+                unsafe { Self(Mem::from_raw(raw as *mut T)) }
+            }
+        }
+
+        unsafe impl<T> Place for $name<T> {
+            type Target = T;
+            type Init = $rc<T>;
+
+            impl_place_deref!(use 0);
+
+            #[inline(always)]
+            unsafe fn assume_init(self) -> Self::Init {
+                // Disable the drop impl, the allocation is now owned by the returned pointer.
+                let mut this = ManuallyDrop::new(self);
+                unsafe { $rc::from_raw(this.0.deref_place_mut().as_mut_ptr()) }
+            }
+        }
+
+        unsafe impl<T> PinPlace for $name<T> {
+            type PinInit = Pin<$rc<T>>;
+
+            #[inline(always)]
+            unsafe fn assume_init_pinned(self) -> Self::PinInit {
+                // SAFETY: the allocation is only freed after dropping the value.
+                unsafe { Pin::new_unchecked(self.assume_init()) }
+            }
+        }
+
+        impl<T> Drop for $name<T> {
+            #[inline]
+            fn drop(&mut self) {
+                let raw = self.0.deref_place_mut().as_mut_ptr() as *const MaybeUninit<T>;
+                drop(unsafe { $rc::from_raw(raw) });
+            }
+        }
+
+        impl<T> Emplace for $rc<T> {
+            type Place = $name<T>;
+
+            #[inline]
+            fn emplace() -> Self::Place {
+                $name::alloc()
+            }
+        }
+
+        impl<T> Slot<$rc<T>> for $rc<MaybeUninit<T>> {
+            type Place = $name<T>;
+
+            #[inline]
+            fn into_place(mut self) -> Self::Place {
+                if $rc::get_mut(&mut self).is_none() {
+                    panic_shared(stringify!($rc))
+                }
+                let raw = $rc::into_raw(self) as *mut T;
+                // SAFETY: we checked above that the allocation is unique.
+                unsafe { $name(Mem::from_raw(raw)) }
+            }
+        }
+    };
+}
+
+impl_rc_mem! {
+    /// The place of a [`Rc<T>`], allocated but not yet initialized.
+    RcMem => Rc
+}
+
+impl_rc_mem! {
+    /// The place of an [`Arc<T>`], allocated but not yet initialized.
+    ArcMem => Arc
+}
+
+#[cold]
+#[inline(never)]
+fn panic_shared(rc: &str) -> ! {
+    panic!("cannot use a shared {rc} as a slot")
+}
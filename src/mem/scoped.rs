@@ -1,7 +1,8 @@
 use core::marker::PhantomData;
 
 use crate::init::Init;
-use crate::place::Place;
+use crate::place::{CastPlace, Place};
+use crate::unsize::{Coercion, UnsizePlace};
 use super::Mem;
 
 // TODO: document
@@ -24,6 +25,30 @@ unsafe impl<'s, T: ?Sized> Place for ScopedMem<'s, T> {
     }
 }
 
+unsafe impl<'s, T: ?Sized, U: ?Sized> UnsizePlace<U> for ScopedMem<'s, T> {
+    type Unsized = ScopedMem<'s, U>;
+
+    #[inline(always)]
+    fn unsize_place(self, coercion: Coercion<T, U>) -> Self::Unsized {
+        ScopedMem {
+            mem: self.mem.unsize(coercion),
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'s, T, U> CastPlace<U> for ScopedMem<'s, T> {
+    type Cast = ScopedMem<'s, U>;
+
+    #[inline(always)]
+    fn cast_place(self) -> Self::Cast {
+        ScopedMem {
+            mem: self.mem.cast(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 // TODO: document
 pub struct Scope<'scope>(PhantomData<fn(&'scope ()) -> &'scope ()>);
 
@@ -0,0 +1,264 @@
+//! Pinned in-place initialization.
+//!
+//! Values built through [`Slot::pin_with`](crate::Slot::pin_with) are pinned as soon as
+//! they are written, and can thus observe their final address during construction.
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+use core::ptr::NonNull;
+
+use crate::place::Place;
+use crate::uninit::{UninitMut, UninitRef};
+
+type Invariant<'s> = PhantomData<fn(&'s ()) -> &'s ()>;
+
+/// A place that can hold pinned values.
+///
+/// # Safety
+///
+/// Once the place is initialized, its memory must not be deallocated nor reused
+/// before the value is dropped, even if the returned pointer is leaked.
+pub unsafe trait PinPlace: Place {
+    type PinInit: Deref<Target = Self::Target>;
+
+    /// # Safety
+    ///
+    /// The place must be initialized.
+    unsafe fn assume_init_pinned(self) -> Self::PinInit;
+}
+
+/// The pinned place handed to [`Slot::pin_with`](crate::Slot::pin_with).
+///
+/// Unlike a [`ScopedMem`](crate::ScopedMem), the value can't be moved after it is written.
+pub struct PinMem<'s, T: ?Sized> {
+    uninit: UninitMut<'s, T>,
+    init: &'s mut bool,
+    _brand: Invariant<'s>,
+}
+
+/// Proof that a [`PinMem`] has been initialized.
+pub struct PinRef<'s, T: ?Sized> {
+    ptr: NonNull<T>,
+    _brand: Invariant<'s>,
+}
+
+impl<'s, T: ?Sized> PinMem<'s, T> {
+    /// The final address of the value.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const T {
+        self.uninit.as_ptr()
+    }
+
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.uninit.as_mut_ptr()
+    }
+
+    /// # Safety
+    ///
+    /// The place must be initialized.
+    #[inline(always)]
+    pub unsafe fn assume_init(mut self) -> PinRef<'s, T> {
+        *self.init = true;
+        PinRef {
+            ptr: self.uninit.as_non_null(),
+            _brand: PhantomData,
+        }
+    }
+}
+
+impl<'s, T> PinMem<'s, T> {
+    #[inline(always)]
+    pub fn set(mut self, value: T) -> PinRef<'s, T> {
+        self.uninit.write(value);
+        // SAFETY: we just wrote the value.
+        unsafe { self.assume_init() }
+    }
+
+    /// Builds the value with the knowledge of its final address.
+    #[inline(always)]
+    pub fn set_with(mut self, f: impl FnOnce(NonNull<T>) -> T) -> PinRef<'s, T> {
+        let value = f(self.uninit.as_non_null());
+        self.set(value)
+    }
+}
+
+impl<'s, T: ?Sized> PinRef<'s, T> {
+    #[inline(always)]
+    pub fn as_ref(&self) -> Pin<&T> {
+        // SAFETY: the value is initialized and pinned.
+        unsafe { Pin::new_unchecked(self.ptr.as_ref()) }
+    }
+
+    #[inline(always)]
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        // SAFETY: the value is initialized and pinned.
+        unsafe { Pin::new_unchecked(self.ptr.as_mut()) }
+    }
+}
+
+/// Drops the value if the initializer unwinds after writing it.
+pub(crate) struct PinGuard<'a, T: ?Sized> {
+    uninit: UninitMut<'a, T>,
+    init: bool,
+}
+
+impl<'a, T: ?Sized> PinGuard<'a, T> {
+    #[inline(always)]
+    pub(crate) fn new(uninit: UninitMut<'a, T>) -> Self {
+        Self { uninit, init: false }
+    }
+
+    #[inline(always)]
+    pub(crate) fn borrow(&mut self) -> PinMem<'_, T> {
+        PinMem {
+            uninit: self.uninit.borrow_mut(),
+            init: &mut self.init,
+            _brand: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn disarm(self) {
+        core::mem::forget(self)
+    }
+}
+
+impl<T: ?Sized> Drop for PinGuard<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.init {
+            // SAFETY: the value is initialized and will never be accessed again.
+            unsafe { self.uninit.drop_in_place() }
+        }
+    }
+}
+
+/// Declares a pinned stack slot, usable as a [`Slot`](crate::Slot) producing
+/// a `Pin<PinOwn<'_, T>>`.
+///
+/// The backing storage is hidden, and drops the value at the end of the enclosing
+/// scope if the [`PinOwn`] was leaked.
+#[macro_export]
+macro_rules! pin_slot {
+    ($name:ident) => {
+        let mut storage = $crate::pin::StackPin::new();
+        // SAFETY: `storage` can't be named, so it is never moved nor leaked.
+        let $name = unsafe { $crate::pin::StackPin::slot(&mut storage) };
+    };
+}
+
+#[doc(hidden)]
+pub struct StackPin<T> {
+    value: MaybeUninit<T>,
+    init: bool,
+}
+
+impl<T> StackPin<T> {
+    #[inline(always)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            value: MaybeUninit::uninit(),
+            init: false,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `this` must be dropped before its memory is reused.
+    #[inline(always)]
+    pub unsafe fn slot(this: &mut Self) -> PinSlot<'_, T> {
+        PinSlot {
+            uninit: UninitMut::from(&mut this.value),
+            init: &mut this.init,
+        }
+    }
+}
+
+impl<T> Drop for StackPin<T> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.init {
+            // SAFETY: the `PinOwn` was leaked, so we're responsible for the value.
+            unsafe { self.value.assume_init_drop() }
+        }
+    }
+}
+
+/// A pinned stack slot, declared with [`pin_slot!`](crate::pin_slot!).
+pub struct PinSlot<'s, T> {
+    uninit: UninitMut<'s, T>,
+    init: &'s mut bool,
+}
+
+/// A pinned value owned by a [`PinSlot`].
+pub struct PinOwn<'s, T> {
+    uninit: UninitMut<'s, T>,
+    init: &'s mut bool,
+}
+
+unsafe impl<'s, T> Place for PinSlot<'s, T> {
+    type Target = T;
+    type Init = Pin<PinOwn<'s, T>>;
+
+    #[inline(always)]
+    fn deref_place(&self) -> UninitRef<'_, Self::Target> {
+        self.uninit.borrow()
+    }
+
+    #[inline(always)]
+    fn deref_place_mut(&mut self) -> UninitMut<'_, Self::Target> {
+        self.uninit.borrow_mut()
+    }
+
+    #[inline(always)]
+    unsafe fn assume_init(self) -> Self::Init {
+        // The storage will drop the value if the `PinOwn` is leaked.
+        *self.init = true;
+        let own = PinOwn {
+            uninit: self.uninit,
+            init: self.init,
+        };
+        // SAFETY: the storage outlives `'s`, and isn't reused before the value is dropped.
+        unsafe { Pin::new_unchecked(own) }
+    }
+}
+
+unsafe impl<'s, T> PinPlace for PinSlot<'s, T> {
+    type PinInit = Self::Init;
+
+    #[inline(always)]
+    unsafe fn assume_init_pinned(self) -> Self::PinInit {
+        unsafe { self.assume_init() }
+    }
+}
+
+impl<T> Deref for PinOwn<'_, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        // SAFETY: per our invariants, we contain a valid `T`.
+        unsafe { self.uninit.as_ref() }
+    }
+}
+
+impl<T> DerefMut for PinOwn<'_, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: per our invariants, we contain a valid `T`.
+        unsafe { self.uninit.as_mut() }
+    }
+}
+
+impl<T> Drop for PinOwn<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Clear the flag first, so that a panicking drop isn't run twice.
+        *self.init = false;
+        unsafe { self.uninit.drop_in_place() }
+    }
+}
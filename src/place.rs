@@ -1,7 +1,14 @@
+use core::future::Future;
 use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ops::Deref;
 
-use crate::uninit::{UninitMut, UninitRef};
+use crate::default::DefaultInPlace;
+use crate::mem::AllocError;
+use crate::init::Init;
+use crate::pin::{PinGuard, PinMem, PinPlace, PinRef};
+use crate::slice::{CollectError, Slice};
+use crate::uninit::{MetaSized, UninitMut, UninitRef};
+use crate::zeroable::Zeroable;
 use crate::{ScopedMem, ScopedRef};
 
 pub trait Slot<V: Deref>: Sized {
@@ -19,6 +26,18 @@ pub trait Slot<V: Deref>: Sized {
         unsafe { place.assume_init() }
     }
 
+    /// Initializes the value by filling it with zeroes.
+    #[inline(always)]
+    fn zeroed(self) -> V
+    where
+        V::Target: Zeroable + MetaSized,
+    {
+        let mut place = self.into_place();
+        place.deref_place_mut().write_zeroes();
+        // SAFETY: the all-zero value is valid.
+        unsafe { place.assume_init() }
+    }
+
     #[inline(always)]
     fn with(
         self,
@@ -32,6 +51,127 @@ pub trait Slot<V: Deref>: Sized {
         }
         unsafe { place.assume_init() }
     }
+
+    #[inline(always)]
+    fn try_with<E>(
+        self,
+        init: impl for<'s> FnOnce(ScopedMem<'s, V::Target>) -> Result<ScopedRef<'s, V::Target>, E>,
+    ) -> Result<V, E> {
+        let mut place = self.into_place();
+        {
+            let_scope!(scope);
+            let out = scope.borrow(&mut place);
+            // On error, `place` is dropped and releases its memory.
+            core::mem::forget(init(out)?);
+        }
+        Ok(unsafe { place.assume_init() })
+    }
+
+    /// Like [`with`](Self::with), but `init` is an async closure.
+    ///
+    /// If the returned future is dropped before completion, the partially
+    /// initialized value is dropped and the place is released.
+    #[inline(always)]
+    fn with_async<F>(self, init: F) -> impl Future<Output = V>
+    where
+        F: for<'s> AsyncFnOnce(ScopedMem<'s, V::Target>) -> ScopedRef<'s, V::Target>,
+    {
+        async move {
+            let mut place = self.into_place();
+            {
+                let_scope!(scope);
+                let out = scope.borrow(&mut place);
+                core::mem::forget(init(out).await);
+            }
+            unsafe { place.assume_init() }
+        }
+    }
+
+    /// Initializes a slice-like place element by element, calling `f` with each index.
+    ///
+    /// If `f` panics, the elements written so far are dropped.
+    #[inline]
+    fn init_from_fn(self, mut f: impl FnMut(usize) -> <Self::Place as SlicePlace>::Elem) -> V
+    where
+        Self::Place: SlicePlace,
+    {
+        let mut slice = Slice::new(self.into_place());
+        while !slice.is_full() {
+            let elem = f(slice.len());
+            slice.push(elem);
+        }
+        Init::finalize(slice.assert_full())
+    }
+
+    /// Initializes a slice-like place with the items of `iter`, which must have
+    /// exactly the length of the place.
+    ///
+    /// On error, the items written so far are dropped; if the iterator is too long,
+    /// one extra item is consumed.
+    #[inline]
+    fn init_from_iter<I>(self, iter: I) -> Result<V, CollectError>
+    where
+        I: IntoIterator<Item = <Self::Place as SlicePlace>::Elem>,
+        Self::Place: SlicePlace,
+    {
+        self.try_init_from_iter(iter.into_iter().map(Ok))
+    }
+
+    /// Like [`init_from_iter`](Self::init_from_iter), but stops at the first error
+    /// yielded by `iter`.
+    #[inline]
+    fn try_init_from_iter<I, E>(self, iter: I) -> Result<V, CollectError<E>>
+    where
+        I: IntoIterator<Item = Result<<Self::Place as SlicePlace>::Elem, E>>,
+        Self::Place: SlicePlace,
+    {
+        let mut slice = Slice::new(self.into_place());
+        let mut iter = iter.into_iter();
+        while !slice.is_full() {
+            match iter.next() {
+                Some(item) => slice.push(item.map_err(CollectError::Iter)?),
+                None => {
+                    let (len, capacity) = (slice.len(), slice.capacity());
+                    return Err(CollectError::TooShort { len, capacity });
+                }
+            }
+        }
+        if iter.next().is_some() {
+            let capacity = slice.capacity();
+            return Err(CollectError::TooLong { capacity });
+        }
+        Ok(Init::finalize(slice.assert_full()))
+    }
+
+    #[inline(always)]
+    fn pin_set(self, value: V::Target) -> <Self::Place as PinPlace>::PinInit
+    where
+        V::Target: Sized,
+        Self::Place: PinPlace,
+    {
+        let mut place = self.into_place();
+        place.deref_place_mut().write(value);
+        unsafe { place.assume_init_pinned() }
+    }
+
+    /// Initializes a pinned value; `init` can observe its final address.
+    #[inline(always)]
+    fn pin_with(
+        self,
+        init: impl for<'s> FnOnce(PinMem<'s, V::Target>) -> PinRef<'s, V::Target>,
+    ) -> <Self::Place as PinPlace>::PinInit
+    where
+        Self::Place: PinPlace,
+    {
+        let mut place = self.into_place();
+        {
+            // If `init` unwinds, the guard drops the value before the place is released.
+            let mut guard = PinGuard::new(place.deref_place_mut());
+            init(guard.borrow());
+            guard.disarm();
+        }
+        unsafe { place.assume_init_pinned() }
+    }
 }
 
 impl<P: Place> Slot<P::Init> for P {
@@ -43,6 +183,12 @@ impl<P: Place> Slot<P::Init> for P {
     }
 }
 
+/// An owned, possibly uninitialized memory location.
+///
+/// # Safety
+///
+/// `deref_place` and `deref_place_mut` must always return the same memory, which must
+/// stay valid until the place is dropped or turned into its [`Init`](Self::Init).
 pub unsafe trait Place: Sized {
     // Technically could be deduced from Self::Init, but makes the trait easier to use.
     type Target: ?Sized;
@@ -52,7 +198,9 @@ pub unsafe trait Place: Sized {
 
     fn deref_place_mut(&mut self) -> UninitMut<'_, Self::Target>;
 
-    // Safety: the place must be initialized.
+    /// # Safety
+    ///
+    /// The place must be initialized.
     unsafe fn assume_init(self) -> Self::Init;
 
     // TODO: should this be unsafe? technically no, as creating a Place
@@ -69,19 +217,104 @@ pub unsafe trait Place: Sized {
     }
 }
 
+/// A place that can be reinterpreted as a place of `U`, reusing its memory.
+///
+/// # Safety
+///
+/// `cast_place` must return a place to the same memory, with the same ownership, and
+/// this memory must be suitably sized and aligned for `U`.
+pub unsafe trait CastPlace<U>: Place {
+    type Cast: Place<Target = U>;
+
+    fn cast_place(self) -> Self::Cast;
+}
+
 pub trait Emplace: Deref {
     type Place: Place<Target = Self::Target, Init = Self>;
 
     fn emplace() -> Self::Place;
+
+    /// Creates a zeroed value; heap places may get zeroed memory from the allocator directly.
+    #[inline]
+    fn emplace_zeroed() -> Self
+    where
+        Self::Target: Zeroable + MetaSized,
+        Self: Sized,
+    {
+        Self::emplace().zeroed()
+    }
+
+    /// Creates a default value, initializing it in place.
+    #[inline]
+    fn emplace_default() -> Self
+    where
+        Self::Target: DefaultInPlace,
+        Self: Sized,
+    {
+        <Self::Target as DefaultInPlace>::default_in_place(Self::emplace())
+    }
+}
+
+/// An [`Emplace`] whose allocation failures can be recovered from.
+///
+/// Not implemented for [`Rc`](alloc::rc::Rc) and [`Arc`](alloc::sync::Arc), which abort
+/// on allocation failure.
+pub trait TryEmplace: Emplace {
+    /// Like [`emplace`](Emplace::emplace), but returns an error if the allocation fails.
+    fn try_emplace() -> Result<Self::Place, AllocError>;
+}
+
+/// Like [`Emplace`], for slices whose length is only known at runtime.
+pub trait EmplaceSlice: Deref {
+    type Place: Place<Target = Self::Target, Init = Self>;
+
+    fn emplace_slice(len: usize) -> Self::Place;
+
+    /// Like [`emplace_slice`](Self::emplace_slice), but returns an error if the allocation fails.
+    fn try_emplace_slice(len: usize) -> Result<Self::Place, AllocError>;
+
+    /// Collects an iterator of known length, without an intermediate buffer.
+    ///
+    /// Fails if the iterator doesn't yield as many items as its reported length.
+    #[inline]
+    fn emplace_from_iter<I>(iter: I) -> Result<Self, CollectError>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        Self::Place: SlicePlace<Elem = I::Item>,
+        Self: Sized,
+    {
+        let iter = iter.into_iter();
+        Self::emplace_slice(iter.len()).init_from_iter(iter)
+    }
+
+    /// Like [`Emplace::emplace_zeroed`], for slices.
+    #[inline]
+    fn emplace_slice_zeroed(len: usize) -> Self
+    where
+        Self::Target: Zeroable + MetaSized,
+        Self: Sized,
+    {
+        Self::emplace_slice(len).zeroed()
+    }
 }
 
 /// A slice-like place. Implemented for places to slices and fixed-sized arrays.
-// TODO: document more
+///
+/// # Safety
+///
+/// `len`, `as_uninit_slice` and `as_uninit_slice_mut` must describe the memory of the
+/// place, viewed as a slice of `Elem`.
 pub unsafe trait SlicePlace: Place {
     type Elem;
 
     fn len(&self) -> usize;
 
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     fn as_uninit_slice(&self) -> &[MaybeUninit<Self::Elem>];
 
     fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<Self::Elem>];
@@ -96,47 +329,39 @@ where
 
     #[inline(always)]
     fn len(&self) -> usize {
-        <Self::Target as sealed::SliceLike>::len(self.deref_place().into())
+        self.deref_place().unsize(sealed::SliceLike::TO_SLICE).len()
     }
 
     #[inline(always)]
     fn as_uninit_slice(&self) -> &[MaybeUninit<Self::Elem>] {
-        let ptr = self.deref_place().into();
-        let len = <Self::Target as sealed::SliceLike>::len(ptr);
-        unsafe { core::slice::from_raw_parts(ptr.cast().as_ptr(), len) }
+        self.deref_place().unsize(sealed::SliceLike::TO_SLICE).into()
     }
 
     #[inline(always)]
     fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<Self::Elem>] {
-        let ptr = self.deref_place().into();
-        let len = <Self::Target as sealed::SliceLike>::len(ptr);
-        unsafe { core::slice::from_raw_parts_mut(ptr.cast().as_ptr(), len) }
+        self.deref_place_mut().unsize(sealed::SliceLike::TO_SLICE).into()
     }
 }
 
 mod sealed {
-    use core::ptr::NonNull;
+    use crate::unsize::Coercion;
 
+    // `SlicePlace` can't be implemented for all `P: UnsizePlace<[T]>` instead: `T` would
+    // be unconstrained by the impl, and unsizing consumes the place while `SlicePlace`
+    // only borrows it. This trait maps each target to its element type, and provides the
+    // coercion to apply to the borrowed memory.
     pub trait SliceLike {
         type Elem;
-        fn len(ptr: NonNull<Self>) -> usize;
+        const TO_SLICE: Coercion<Self, [Self::Elem]>;
     }
 
     impl<const N: usize, T> SliceLike for [T; N] {
         type Elem = T;
-
-        #[inline(always)]
-        fn len(_: NonNull<Self>) -> usize {
-            N
-        }
+        const TO_SLICE: Coercion<Self, [T]> = Coercion::to_slice();
     }
 
     impl<T> SliceLike for [T] {
         type Elem = T;
-
-        #[inline(always)]
-        fn len(ptr: NonNull<Self>) -> usize {
-            ptr.len()
-        }
+        const TO_SLICE: Coercion<Self, [T]> = Coercion::identity();
     }
 }
@@ -0,0 +1,234 @@
+//! Field-by-field initialization of struct places.
+//!
+//! Structs declared through the [`project!`](crate::project!) macro implement [`Project`],
+//! which allows splitting a [`Projection`] of any place into one [`FieldMem`] per field.
+//! Tuples implement it too, with a tuple of [`FieldMem`]s as their fields.
+
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+use crate::place::Place;
+use crate::uninit::{UninitMut, UninitRef};
+
+/// A struct that can be initialized one field at a time.
+///
+/// Use the [`project!`](crate::project!) macro instead of implementing this trait manually.
+///
+/// # Safety
+///
+/// - `project` must return disjoint places for every field of the struct, each of them
+///   associated with its own flag in `flags`;
+/// - `drop_fields` must drop exactly the fields whose flag is set;
+/// - a struct whose flags are all set must be fully initialized.
+pub unsafe trait Project: Sized {
+    /// One initialization flag per field.
+    type Flags: AsRef<[bool]>;
+
+    /// The flags of a fully uninitialized struct.
+    const UNINIT: Self::Flags;
+
+    /// The field places, usually a struct with one [`FieldMem`] per field.
+    type Fields<'a>
+    where
+        Self: 'a;
+
+    /// # Safety
+    ///
+    /// `flags` must track which fields of `this` are initialized.
+    unsafe fn project<'a>(this: UninitMut<'a, Self>, flags: &'a mut Self::Flags) -> Self::Fields<'a>;
+
+    /// # Safety
+    ///
+    /// `flags` must track which fields of `this` are initialized; these are logically
+    /// uninitialized afterwards.
+    unsafe fn drop_fields(this: UninitMut<'_, Self>, flags: &Self::Flags);
+}
+
+/// The place of a single struct field, handed out by [`Projection::fields`].
+pub struct FieldMem<'a, T> {
+    uninit: UninitMut<'a, T>,
+    init: &'a mut bool,
+}
+
+impl<'a, T> FieldMem<'a, T> {
+    /// # Safety
+    ///
+    /// `init` must be set only if `uninit` contains a valid `T`.
+    #[doc(hidden)]
+    #[inline(always)]
+    pub unsafe fn new(uninit: UninitMut<'a, T>, init: &'a mut bool) -> Self {
+        Self { uninit, init }
+    }
+}
+
+unsafe impl<'a, T: 'a> Place for FieldMem<'a, T> {
+    type Target = T;
+    type Init = &'a mut T;
+
+    #[inline(always)]
+    fn deref_place(&self) -> UninitRef<'_, Self::Target> {
+        self.uninit.borrow()
+    }
+
+    #[inline(always)]
+    fn deref_place_mut(&mut self) -> UninitMut<'_, Self::Target> {
+        // The field may be overwritten or dropped through the returned reference, so
+        // it no longer counts as initialized; any previous value is leaked.
+        *self.init = false;
+        self.uninit.borrow_mut()
+    }
+
+    #[inline(always)]
+    unsafe fn assume_init(self) -> Self::Init {
+        *self.init = true;
+        unsafe { self.uninit.into_mut() }
+    }
+}
+
+/// A struct place whose fields are initialized separately.
+///
+/// [`fields`](Self::fields) splits the place into one place per field, and the
+/// projection records which fields were initialized through them. Once all of them are,
+/// [`finish`](Self::finish) returns the initialized struct. Dropping an unfinished
+/// projection drops the initialized fields only.
+pub struct Projection<P>
+where
+    P: Place,
+    P::Target: Project,
+{
+    place: P,
+    flags: <P::Target as Project>::Flags,
+    // We logically own the initialized fields.
+    _marker: PhantomData<P::Init>,
+}
+
+impl<T, P> Projection<P>
+where
+    P: Place<Target = T>,
+    T: Project,
+{
+    #[inline(always)]
+    pub fn new(place: P) -> Self {
+        Self {
+            place,
+            flags: T::UNINIT,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the places of all fields.
+    ///
+    /// Fields that were already initialized are handed out again. They stay initialized
+    /// until their place is written to, at which point their previous value is leaked.
+    #[inline(always)]
+    pub fn fields(&mut self) -> T::Fields<'_> {
+        // SAFETY: the flags are tied to the fields of this place.
+        unsafe { T::project(self.place.deref_place_mut(), &mut self.flags) }
+    }
+
+    #[inline(always)]
+    pub fn is_init(&self) -> bool {
+        self.flags.as_ref().iter().all(|init| *init)
+    }
+
+    #[inline]
+    pub fn finish(self) -> P::Init {
+        if self.is_init() {
+            // SAFETY: all fields are initialized, so the whole struct is.
+            unsafe { self.forget().assume_init() }
+        } else {
+            panic_not_init(self.flags.as_ref())
+        }
+    }
+
+    #[inline]
+    pub fn drop(mut self) -> P {
+        // Disarm the projection first, so that a panicking destructor leaks the
+        // remaining fields instead of dropping them twice.
+        let flags = mem::replace(&mut self.flags, T::UNINIT);
+        let mut place = self.forget();
+        // SAFETY: the flags track exactly which fields are initialized.
+        unsafe { T::drop_fields(place.deref_place_mut(), &flags) };
+        place
+    }
+
+    #[inline(always)]
+    pub fn forget(self) -> P {
+        // Disable the drop impl.
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` isn't accessed nor dropped after these lines.
+        unsafe {
+            ptr::drop_in_place(&mut this.flags);
+            ptr::read(&this.place)
+        }
+    }
+}
+
+impl<P> Drop for Projection<P>
+where
+    P: Place,
+    P::Target: Project,
+{
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the flags track exactly which fields are initialized.
+        unsafe { <P::Target as Project>::drop_fields(self.place.deref_place_mut(), &self.flags) }
+    }
+}
+
+macro_rules! impl_project_tuple {
+    ($($T:ident $f:ident $idx:tt)*) => {
+        // SAFETY: each field gets its own flag, and is dropped only if it is set.
+        unsafe impl<$($T),*> Project for ($($T,)*) {
+            type Flags = [bool; <[&str]>::len(&[$(stringify!($T)),*])];
+
+            const UNINIT: Self::Flags = [false; <[&str]>::len(&[$(stringify!($T)),*])];
+
+            type Fields<'a> = ($(FieldMem<'a, $T>,)*)
+            where
+                Self: 'a;
+
+            #[inline(always)]
+            unsafe fn project<'a>(
+                mut this: UninitMut<'a, Self>,
+                flags: &'a mut Self::Flags,
+            ) -> Self::Fields<'a> {
+                let ptr = this.as_mut_ptr();
+                let [$($f),*] = flags;
+                ($(
+                    unsafe {
+                        FieldMem::new(UninitMut::new_unchecked(ptr::addr_of_mut!((*ptr).$idx)), $f)
+                    },
+                )*)
+            }
+
+            #[inline]
+            unsafe fn drop_fields(mut this: UninitMut<'_, Self>, flags: &Self::Flags) {
+                let ptr = this.as_mut_ptr();
+                let [$($f),*] = flags;
+                $(
+                    if *$f {
+                        unsafe { ptr::drop_in_place(ptr::addr_of_mut!((*ptr).$idx)) }
+                    }
+                )*
+            }
+        }
+    };
+}
+
+impl_project_tuple!(A a 0);
+impl_project_tuple!(A a 0 B b 1);
+impl_project_tuple!(A a 0 B b 1 C c 2);
+impl_project_tuple!(A a 0 B b 1 C c 2 D d 3);
+impl_project_tuple!(A a 0 B b 1 C c 2 D d 3 E e 4);
+impl_project_tuple!(A a 0 B b 1 C c 2 D d 3 E e 4 F f 5);
+impl_project_tuple!(A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6);
+impl_project_tuple!(A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7);
+
+#[cold]
+#[inline(never)]
+fn panic_not_init(flags: &[bool]) -> ! {
+    let missing = flags.iter().filter(|init| !**init).count();
+    panic!("struct isn't fully initialized ({missing} of {} fields missing)", flags.len())
+}
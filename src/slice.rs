@@ -1,13 +1,21 @@
+use core::convert::Infallible;
+use core::fmt;
 use core::marker::PhantomData;
 use core::mem::{ManuallyDrop, MaybeUninit};
-use core::ops::{Deref, DerefMut};
+use core::iter::FusedIterator;
+use core::ops::{Bound, Deref, DerefMut, RangeBounds};
 use core::{ptr, slice};
 
 use crate::init::Init;
 use crate::place::{Place, SlicePlace, Slot};
 
-// TODO: document
-// TODO: implement the full Vec API.
+/// A slice place being filled from the front, with an API similar to [`Vec`].
+///
+/// The capacity is fixed by the underlying place. Once all of its elements are
+/// initialized, [`assert_full`](Self::assert_full) turns the slice into an [`Init`];
+/// dropping it before drops the initialized elements only.
+///
+/// [`Vec`]: alloc::vec::Vec
 pub struct Slice<P: SlicePlace> {
     place: P,
     len: usize,
@@ -20,7 +28,7 @@ where
     P: SlicePlace<Elem = T>,
 {
     #[inline(always)]
-    pub fn new(place: P) -> Self {
+    pub const fn new(place: P) -> Self {
         Self {
             place,
             len: 0,
@@ -29,10 +37,15 @@ where
     }
 
     #[inline(always)]
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.len
     }
 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     #[inline(always)]
     pub fn capacity(&self) -> usize {
         self.place.len()
@@ -53,11 +66,21 @@ where
         self.place.deref_place_mut().as_mut_ptr().cast()
     }
 
+    /// Appends an element to the back of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is full.
     #[inline]
     pub fn push(&mut self, elem: T) {
         self.emplace().set(elem);
     }
 
+    /// Returns the place of a new element at the back of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is full.
     #[inline]
     pub fn emplace(&mut self) -> SliceHole<'_, T> {
         let pos = self.len();
@@ -68,6 +91,12 @@ where
         }
     }
 
+    /// Returns the place of a new element at position `pos`, shifting all elements after
+    /// it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is full, or if `pos > len`.
     #[inline]
     pub fn emplace_at(&mut self, pos: usize) -> SliceHole<'_, T> {
         let len = self.len();
@@ -105,6 +134,239 @@ where
         }
     }
 
+    /// Inserts an element at position `pos`, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is full, or if `pos > len`.
+    #[inline]
+    pub fn insert(&mut self, pos: usize, elem: T) {
+        self.emplace_at(pos).set(elem);
+    }
+
+    /// Removes and returns the element at position `pos`, shifting all elements after it
+    /// to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos >= len`.
+    #[inline]
+    pub fn remove(&mut self, pos: usize) -> T {
+        let len = self.len();
+        if pos >= len {
+            panic_out_of_bounds(pos, len)
+        }
+        unsafe {
+            let ptr = self.as_mut_ptr().add(pos);
+            let val = ptr::read(ptr);
+            ptr::copy(ptr.add(1), ptr, len - pos - 1);
+            self.len = len - 1;
+            val
+        }
+    }
+
+    /// Removes and returns the element at position `pos`, replacing it with the last
+    /// element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos >= len`.
+    #[inline]
+    pub fn swap_remove(&mut self, pos: usize) -> T {
+        let len = self.len();
+        if pos >= len {
+            panic_out_of_bounds(pos, len)
+        }
+        unsafe {
+            let base = self.as_mut_ptr();
+            let val = ptr::read(base.add(pos));
+            ptr::copy(base.add(len - 1), base.add(pos), 1);
+            self.len = len - 1;
+            val
+        }
+    }
+
+    /// Drops the elements after the first `len`, if any.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let old_len = core::mem::replace(&mut self.len, len);
+        // SAFETY: the removed elements are now outside of the slice, so drop them.
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), old_len - len);
+            ptr::drop_in_place(tail);
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, in their original order.
+    #[inline]
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Like [`retain`](Self::retain), but `f` can mutate the elements.
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        let ptr = self.as_mut_ptr();
+        let original_len = core::mem::replace(&mut self.len, 0);
+        // If `f` or a drop panics, the guard closes the gap and fixes the length.
+        let mut gap = Gap {
+            ptr,
+            len: &mut self.len,
+            read: 0,
+            write: 0,
+            original_len,
+        };
+
+        while gap.read < original_len {
+            unsafe {
+                let cur = ptr.add(gap.read);
+                if f(&mut *cur) {
+                    if gap.read != gap.write {
+                        ptr::copy_nonoverlapping(cur, ptr.add(gap.write), 1);
+                    }
+                    gap.write += 1;
+                    gap.read += 1;
+                } else {
+                    gap.read += 1;
+                    ptr::drop_in_place(cur);
+                }
+            }
+        }
+    }
+
+    /// Removes consecutive equal elements.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq>(&mut self, mut key: impl FnMut(&mut T) -> K) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same_bucket(elem, previous)` returns `true`.
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        let ptr = self.as_mut_ptr();
+        let original_len = self.len;
+        if original_len <= 1 {
+            return;
+        }
+
+        self.len = 0;
+        // If `same_bucket` or a drop panics, the guard closes the gap and fixes the length.
+        let mut gap = Gap {
+            ptr,
+            len: &mut self.len,
+            read: 1,
+            write: 1,
+            original_len,
+        };
+
+        while gap.read < original_len {
+            unsafe {
+                let cur = ptr.add(gap.read);
+                let prev = ptr.add(gap.write - 1);
+                if same_bucket(&mut *cur, &mut *prev) {
+                    gap.read += 1;
+                    ptr::drop_in_place(cur);
+                } else {
+                    if gap.read != gap.write {
+                        ptr::copy_nonoverlapping(cur, ptr.add(gap.write), 1);
+                    }
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+        }
+    }
+
+    /// Removes the elements in `range`, returning them as an iterator.
+    ///
+    /// The elements after the range are shifted back when the iterator is dropped. If it
+    /// is leaked instead, they are leaked too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or ends after `len`.
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, T> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).unwrap_or_else(|| panic_range_overflow()),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).unwrap_or_else(|| panic_range_overflow()),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        if start > end || end > len {
+            panic_range(start, end, len)
+        }
+
+        let ptr = self.as_mut_ptr();
+        // 'Pre-poop our pants' so that leaking the iterator leaks all drained elements.
+        self.len = start;
+        Drain {
+            ptr,
+            len: &mut self.len,
+            front: start,
+            back: end,
+            tail_start: end,
+            tail_len: len - end,
+        }
+    }
+
+    /// Appends clones of the elements of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice becomes full before all elements are appended.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        for elem in other {
+            self.push(elem.clone());
+        }
+    }
+
+    /// Moves the elements in `at..` into a new `Slice` backed by `place`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`, or if `place` is too small for the moved elements.
+    pub fn split_off<Q>(&mut self, at: usize, place: Q) -> Slice<Q>
+    where
+        Q: SlicePlace<Elem = T>,
+    {
+        let len = self.len();
+        if at > len {
+            panic_out_of_bounds(at, len)
+        }
+
+        let mut other = Slice::new(place);
+        let other_len = len - at;
+        if other_len > other.capacity() {
+            panic_slice_too_small(other_len, other.capacity())
+        }
+
+        unsafe {
+            self.len = at;
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), other_len);
+            other.len = other_len;
+        }
+        other
+    }
+
     #[inline(always)]
     pub fn drop(mut self) -> P {
         self.clear();
@@ -112,11 +374,13 @@ where
     }
 
     #[inline(always)]
-    pub fn forget(self) -> P {
+    pub const fn forget(self) -> P {
         // Disable the drop impl.
         let this = ManuallyDrop::new(self);
+        // Go through raw pointers, as `ManuallyDrop`'s `Deref` isn't const.
+        let this = ptr::from_ref(&this).cast::<Self>();
         // SAFETY: `this` isn't accessed nor dropped after this line.
-        unsafe { ptr::read(&this.place) }
+        unsafe { ptr::read(ptr::addr_of!((*this).place)) }
     }
 
     // TODO: see Place::leak
@@ -165,6 +429,88 @@ impl<P: SlicePlace> Drop for Slice<P> {
     }
 }
 
+/// # Panics
+///
+/// Panics if the slice becomes full before the iterator is exhausted.
+impl<P: SlicePlace> Extend<P::Elem> for Slice<P> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = P::Elem>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<P: SlicePlace> IntoIterator for Slice<P> {
+    type Item = P::Elem;
+    type IntoIter = IntoIter<P>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let end = self.len();
+        IntoIter {
+            place: self.forget(),
+            front: 0,
+            back: end,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, P: SlicePlace> IntoIterator for &'a Slice<P> {
+    type Item = &'a P::Elem;
+    type IntoIter = slice::Iter<'a, P::Elem>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, P: SlicePlace> IntoIterator for &'a mut Slice<P> {
+    type Item = &'a mut P::Elem;
+    type IntoIter = slice::IterMut<'a, P::Elem>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// The error returned when collecting an iterator into a slice place fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollectError<E = Infallible> {
+    /// The iterator ended after `len` items.
+    TooShort { len: usize, capacity: usize },
+    /// The iterator had more than `capacity` items.
+    TooLong { capacity: usize },
+    /// The iterator yielded an error.
+    Iter(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CollectError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort { len, capacity } => {
+                write!(f, "iterator is too short (len: {len}, capacity: {capacity})")
+            }
+            Self::TooLong { capacity } => {
+                write!(f, "iterator is too long (capacity: {capacity})")
+            }
+            Self::Iter(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for CollectError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Iter(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 #[cold]
 #[inline(never)]
 fn panic_slice_full(len: usize) -> ! {
@@ -177,6 +523,208 @@ fn panic_slice_not_full(len: usize, cap: usize) -> ! {
     panic!("slice isn't full (len: {len}, capacity: {cap})")
 }
 
+#[cold]
+#[inline(never)]
+fn panic_out_of_bounds(pos: usize, len: usize) -> ! {
+    panic!("index out of bounds (index: {pos}, len: {len})")
+}
+
+#[cold]
+#[inline(never)]
+fn panic_slice_too_small(len: usize, cap: usize) -> ! {
+    panic!("slice is too small (len: {len}, capacity: {cap})")
+}
+
+#[cold]
+#[inline(never)]
+fn panic_range(start: usize, end: usize, len: usize) -> ! {
+    panic!("invalid range (range: {start}..{end}, len: {len})")
+}
+
+#[cold]
+#[inline(never)]
+fn panic_range_overflow() -> ! {
+    panic!("range bound overflows usize")
+}
+
+/// Elements in `write..read` have been moved out; `read..original_len` are untouched.
+struct Gap<'a, T> {
+    ptr: *mut T,
+    len: &'a mut usize,
+    read: usize,
+    write: usize,
+    original_len: usize,
+}
+
+impl<T> Drop for Gap<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Shift back the unprocessed elements and fix the length
+        let tail = self.original_len - self.read;
+        unsafe {
+            if self.read != self.write {
+                ptr::copy(self.ptr.add(self.read), self.ptr.add(self.write), tail);
+            }
+            *self.len = self.write + tail;
+        }
+    }
+}
+
+/// A draining iterator over a [`Slice`], created by [`Slice::drain`].
+pub struct Drain<'a, T> {
+    ptr: *mut T,
+    len: &'a mut usize,
+    // Remaining elements to yield.
+    front: usize,
+    back: usize,
+    // Elements to move back when the iterator is dropped.
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<T> Drain<'_, T> {
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.add(self.front), self.back - self.front) }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.front < self.back {
+            let val = unsafe { ptr::read(self.ptr.add(self.front)) };
+            self.front += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { ptr::read(self.ptr.add(self.back)) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        // Moves back the tail, even if dropping the remaining elements panics.
+        struct MoveTail<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<T> Drop for MoveTail<'_, '_, T> {
+            #[inline]
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let start = *drain.len;
+                unsafe {
+                    if drain.tail_start != start {
+                        let ptr = drain.ptr;
+                        ptr::copy(ptr.add(drain.tail_start), ptr.add(start), drain.tail_len);
+                    }
+                    *drain.len = start + drain.tail_len;
+                }
+            }
+        }
+
+        let guard = MoveTail(self);
+        let remaining = core::mem::replace(&mut guard.0.front, guard.0.back)..guard.0.back;
+        unsafe {
+            let ptr = guard.0.ptr.add(remaining.start);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, remaining.len()));
+        }
+    }
+}
+
+/// An owning iterator over the elements of a [`Slice`].
+pub struct IntoIter<P: SlicePlace> {
+    place: P,
+    // Remaining elements to yield.
+    front: usize,
+    back: usize,
+    // We logically own the remaining elements.
+    _marker: PhantomData<P::Init>,
+}
+
+impl<P: SlicePlace> IntoIter<P> {
+    #[inline(always)]
+    fn as_mut_ptr(&mut self) -> *mut P::Elem {
+        self.place.deref_place_mut().as_mut_ptr().cast()
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[P::Elem] {
+        let ptr = self.place.deref_place().as_ptr().cast::<P::Elem>();
+        unsafe { slice::from_raw_parts(ptr.add(self.front), self.back - self.front) }
+    }
+}
+
+impl<P: SlicePlace> Iterator for IntoIter<P> {
+    type Item = P::Elem;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let val = unsafe { ptr::read(self.as_mut_ptr().add(self.front)) };
+            self.front += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+
+impl<P: SlicePlace> DoubleEndedIterator for IntoIter<P> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { ptr::read(self.as_mut_ptr().add(self.back)) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: SlicePlace> ExactSizeIterator for IntoIter<P> {}
+impl<P: SlicePlace> FusedIterator for IntoIter<P> {}
+
+impl<P: SlicePlace> Drop for IntoIter<P> {
+    #[inline]
+    fn drop(&mut self) {
+        let remaining = core::mem::replace(&mut self.front, self.back)..self.back;
+        // SAFETY: the remaining elements are never accessed again.
+        unsafe {
+            let ptr = self.as_mut_ptr().add(remaining.start);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, remaining.len()));
+        }
+    }
+}
+
 pub struct SliceHole<'a, T> {
     len: &'a mut usize,
     prefix: &'a mut [T],
@@ -222,7 +770,7 @@ impl<'a, T> SliceHole<'a, T> {
     }
 
     #[inline(always)]
-    pub fn split_prefix(mut self) -> (&'a mut [T], impl Place<Init=&'a mut T>) {
+    pub fn split_prefix(mut self) -> (&'a mut [T], impl Place<Target=T, Init=&'a mut T>) {
         (core::mem::take(&mut self.prefix), self)
     }
 }
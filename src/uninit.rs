@@ -4,10 +4,15 @@
 // from the enclosing method.
 #![allow(unsafe_op_in_unsafe_fn)]
 
+use core::alloc::Layout;
 use core::fmt;
+use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ptr::{self, NonNull};
+use core::slice;
+
+use crate::unsize::Coercion;
 
 macro_rules! make {
     ($name:ident; $ptr:expr) => {
@@ -18,6 +23,39 @@ macro_rules! make {
     };
 }
 
+/// A type whose layout can be computed from the metadata of a pointer to it, without
+/// reading the (maybe uninitialized) pointee.
+///
+/// Implemented for all sized types, slices and `str`.
+///
+/// # Safety
+///
+/// `layout_of` must return the layout of the value behind `ptr`.
+pub unsafe trait MetaSized {
+    fn layout_of(ptr: *const Self) -> Layout;
+}
+
+unsafe impl<T> MetaSized for T {
+    #[inline(always)]
+    fn layout_of(_: *const Self) -> Layout {
+        Layout::new::<T>()
+    }
+}
+
+unsafe impl<T> MetaSized for [T] {
+    #[inline(always)]
+    fn layout_of(ptr: *const Self) -> Layout {
+        Layout::array::<T>(ptr.len()).unwrap_or_else(|_| panic_too_large())
+    }
+}
+
+unsafe impl MetaSized for str {
+    #[inline(always)]
+    fn layout_of(ptr: *const Self) -> Layout {
+        <[u8]>::layout_of(ptr as *const [u8])
+    }
+}
+
 // TODO: document
 #[repr(transparent)]
 pub struct UninitRef<'a, T: ?Sized> {
@@ -48,51 +86,92 @@ unsafe impl<T: Sync + ?Sized> Sync for UninitMut<'_, T> {}
 unsafe impl<T: Sync + ?Sized> Send for UninitMut<'_, T> {}
 
 impl<'a, T: ?Sized> UninitRef<'a, T> {
-    // SAFETY: ptr must be live and immutably borrowed during `'a`.
+    /// # Safety
+    ///
+    /// `ptr` must be live and immutably borrowed during `'a`.
     #[inline(always)]
-    pub unsafe fn new_unchecked(ptr: *const T) -> Self {
+    pub const unsafe fn new_unchecked(ptr: *const T) -> Self {
         make!(Self; NonNull::new_unchecked(ptr as *mut _))
     }
 
     #[inline(always)]
-    pub fn as_ptr(&self) -> *const T {
+    pub const fn as_ptr(&self) -> *const T {
         self.ptr.as_ptr()
     }
 
     #[inline(always)]
-    pub fn as_non_null(&self) -> NonNull<T> {
+    pub const fn as_non_null(&self) -> NonNull<T> {
         self.ptr
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`.
     #[inline(always)]
-    pub unsafe fn as_ref(&self) -> &T {
+    pub const unsafe fn as_ref(&self) -> &T {
         self.ptr.as_ref()
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`.
     #[inline(always)]
-    pub unsafe fn into_ref(self) -> &'a T {
+    pub const unsafe fn into_ref(self) -> &'a T {
         self.ptr.as_ref()
     }
 
+    /// # Safety
+    ///
+    /// The memory must stay live and borrowed as before during `'b`.
     #[inline(always)]
-    pub unsafe fn transmute_lt<'b>(self) -> UninitRef<'b, T> {
+    pub const unsafe fn transmute_lt<'b>(self) -> UninitRef<'b, T> {
         make!(UninitRef; self.ptr)
     }
+
+    #[inline(always)]
+    pub fn unsize<U: ?Sized>(self, coercion: Coercion<T, U>) -> UninitRef<'a, U> {
+        let ptr = coercion.coerce(self.ptr.as_ptr());
+        make!(UninitRef; unsafe { NonNull::new_unchecked(ptr) })
+    }
+
+}
+
+impl<'a, T: ?Sized + MetaSized> UninitRef<'a, T> {
+    /// The size of the pointee, as given by its type and pointer metadata.
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.layout().size()
+    }
+
+    /// The alignment of the pointee, as given by its type and pointer metadata.
+    #[inline(always)]
+    pub fn align(&self) -> usize {
+        self.layout().align()
+    }
+
+    #[inline(always)]
+    pub fn layout(&self) -> Layout {
+        T::layout_of(self.ptr.as_ptr())
+    }
 }
 
 impl<'a, T: Sized> UninitRef<'a, T> {
     #[inline(always)]
-    pub fn as_uninit(&self) -> &MaybeUninit<T> {
+    pub const fn as_uninit(&self) -> &MaybeUninit<T> {
         unsafe { self.ptr.cast().as_ref() }
     }
 
     #[inline(always)]
-    pub fn into_uninit(self) -> &'a MaybeUninit<T> {
+    pub const fn into_uninit(self) -> &'a MaybeUninit<T> {
         unsafe { self.ptr.cast().as_ref() }
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`, which is duplicated by the read;
+    /// the caller must make sure that only one of the copies is dropped.
     #[inline(always)]
-    pub unsafe fn read(self) -> T {
+    pub const unsafe fn read(self) -> T {
         ptr::read(self.ptr.as_ptr() as *const T)
     }
 }
@@ -125,81 +204,134 @@ impl<T: ?Sized> fmt::Debug for UninitRef<'_, T> {
 }
 
 impl<'a, T: ?Sized> UninitMut<'a, T> {
-    // SAFETY: ptr must be live and unaliased during `'a`.
+    /// # Safety
+    ///
+    /// `ptr` must be live and unaliased during `'a`.
     #[inline(always)]
-    pub unsafe fn new_unchecked(ptr: *mut T) -> Self {
+    pub const unsafe fn new_unchecked(ptr: *mut T) -> Self {
         make!(Self; NonNull::new_unchecked(ptr))
     }
 
     #[inline(always)]
-    pub fn borrow(&self) -> UninitRef<'_, T> {
+    pub const fn borrow(&self) -> UninitRef<'_, T> {
         make!(UninitRef; self.ptr)
     }
 
     #[inline(always)]
-    pub fn borrow_mut(&mut self) -> UninitMut<'_, T> {
+    pub const fn borrow_mut(&mut self) -> UninitMut<'_, T> {
         make!(UninitMut; self.ptr)
     }
 
     #[inline(always)]
-    pub fn as_ptr(&self) -> *const T {
+    pub const fn as_ptr(&self) -> *const T {
         self.ptr.as_ptr()
     }
 
     #[inline(always)]
-    pub fn as_mut_ptr(&mut self) -> *mut T {
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
         self.ptr.as_ptr()
     }
 
     #[inline(always)]
-    pub fn as_non_null(&mut self) -> NonNull<T> {
+    pub const fn as_non_null(&mut self) -> NonNull<T> {
         self.ptr
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`.
     #[inline(always)]
-    pub unsafe fn as_ref(&self) -> &T {
+    pub const unsafe fn as_ref(&self) -> &T {
         self.ptr.as_ref()
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`.
     #[inline(always)]
-    pub unsafe fn as_mut(&mut self) -> &mut T {
+    pub const unsafe fn as_mut(&mut self) -> &mut T {
         self.ptr.as_mut()
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`.
     #[inline(always)]
-    pub unsafe fn into_mut(mut self) -> &'a mut T {
+    pub const unsafe fn into_mut(mut self) -> &'a mut T {
         self.ptr.as_mut()
     }
 
+    /// # Safety
+    ///
+    /// The memory must stay live and borrowed as before during `'b`.
     #[inline(always)]
-    pub unsafe fn transmute_lt<'b>(self) -> UninitMut<'b, T> {
+    pub const unsafe fn transmute_lt<'b>(self) -> UninitMut<'b, T> {
         make!(UninitMut; self.ptr)
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`, which is logically uninitialized afterwards.
     #[inline(always)]
     pub unsafe fn drop_in_place(&mut self) {
         ptr::drop_in_place(self.ptr.as_ptr())
     }
+
+    #[inline(always)]
+    pub fn unsize<U: ?Sized>(self, coercion: Coercion<T, U>) -> UninitMut<'a, U> {
+        let ptr = coercion.coerce(self.ptr.as_ptr());
+        make!(UninitMut; unsafe { NonNull::new_unchecked(ptr) })
+    }
+
+}
+
+impl<'a, T: ?Sized + MetaSized> UninitMut<'a, T> {
+    /// The size of the pointee, see [`UninitRef::size`].
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.borrow().size()
+    }
+
+    /// The alignment of the pointee, see [`UninitRef::align`].
+    #[inline(always)]
+    pub fn align(&self) -> usize {
+        self.borrow().align()
+    }
+
+    #[inline(always)]
+    pub fn layout(&self) -> Layout {
+        self.borrow().layout()
+    }
+
+    /// Fills the pointee with zero bytes.
+    #[inline(always)]
+    pub fn write_zeroes(&mut self) {
+        unsafe { ptr::write_bytes(self.ptr.as_ptr().cast::<u8>(), 0, self.size()) }
+    }
 }
 
 impl<'a, T: Sized> UninitMut<'a, T> {
     #[inline(always)]
-    pub fn as_uninit(&mut self) -> &mut MaybeUninit<T> {
+    pub const fn as_uninit(&mut self) -> &mut MaybeUninit<T> {
         unsafe { self.ptr.cast().as_mut() }
     }
 
     #[inline(always)]
-    pub fn into_uninit(self) -> &'a mut MaybeUninit<T> {
+    pub const fn into_uninit(self) -> &'a mut MaybeUninit<T> {
         unsafe { self.ptr.cast().as_mut() }
     }
 
+    /// # Safety
+    ///
+    /// The memory must contain a valid `T`, which is duplicated by the read;
+    /// the caller must make sure that only one of the copies is dropped.
     #[inline(always)]
-    pub unsafe fn read(&self) -> T {
+    pub const unsafe fn read(&self) -> T {
         ptr::read(self.ptr.as_ptr() as *const T)
     }
 
     #[inline(always)]
-    pub fn write(&mut self, value: T) -> &mut T {
+    pub const fn write(&mut self, value: T) -> &mut T {
         unsafe {
             let ptr = self.ptr.as_ptr();
             ptr::write(ptr, value);
@@ -235,6 +367,212 @@ impl<T: ?Sized> fmt::Debug for UninitMut<'_, T> {
     }
 }
 
-// TODO: methods on Uninit{Mut, Ref}<[T]>?
-// TODO: Unsize polyfill to replace ad-hoc SliceLike trait?
-// TODO: MetaSized trait to get the layout of a Uninit{Ref, Mut} when possible?
+impl<'a, T> UninitRef<'a, [T]> {
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.ptr.len()
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    pub const fn as_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr().cast(), self.len()) }
+    }
+
+    #[inline(always)]
+    pub const fn into_uninit_slice(self) -> &'a [MaybeUninit<T>] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr().cast(), self.len()) }
+    }
+
+    #[inline(always)]
+    pub fn get(self, index: usize) -> Option<UninitRef<'a, T>> {
+        self.into_uninit_slice().get(index).map(UninitRef::from)
+    }
+
+    #[inline(always)]
+    pub fn split_at(self, mid: usize) -> (UninitRef<'a, [T]>, UninitRef<'a, [T]>) {
+        let (left, right) = self.into_uninit_slice().split_at(mid);
+        (left.into(), right.into())
+    }
+
+    #[inline(always)]
+    pub fn iter(self) -> Iter<'a, T> {
+        Iter(self.into_uninit_slice().iter())
+    }
+}
+
+impl<'a, T> UninitMut<'a, [T]> {
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.ptr.len()
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    pub const fn as_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        self.borrow().into_uninit_slice()
+    }
+
+    #[inline(always)]
+    pub const fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr().cast(), self.len()) }
+    }
+
+    #[inline(always)]
+    pub const fn into_uninit_slice(self) -> &'a mut [MaybeUninit<T>] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr().cast(), self.len()) }
+    }
+
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<UninitRef<'_, T>> {
+        self.borrow().get(index)
+    }
+
+    #[inline(always)]
+    pub fn get_mut(&mut self, index: usize) -> Option<UninitMut<'_, T>> {
+        self.borrow_mut().into_get_mut(index)
+    }
+
+    #[inline(always)]
+    pub fn into_get_mut(self, index: usize) -> Option<UninitMut<'a, T>> {
+        self.into_uninit_slice().get_mut(index).map(UninitMut::from)
+    }
+
+    #[inline(always)]
+    pub fn split_at_mut(&mut self, mid: usize) -> (UninitMut<'_, [T]>, UninitMut<'_, [T]>) {
+        self.borrow_mut().into_split_at_mut(mid)
+    }
+
+    #[inline(always)]
+    pub fn into_split_at_mut(self, mid: usize) -> (UninitMut<'a, [T]>, UninitMut<'a, [T]>) {
+        let (left, right) = self.into_uninit_slice().split_at_mut(mid);
+        (left.into(), right.into())
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.borrow().iter()
+    }
+
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut(self.as_uninit_slice_mut().iter_mut())
+    }
+
+    #[inline(always)]
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T> {
+        ChunksMut(self.as_uninit_slice_mut().chunks_mut(chunk_size))
+    }
+}
+
+impl<'a, T> From<&'a [MaybeUninit<T>]> for UninitRef<'a, [T]> {
+    #[inline(always)]
+    fn from(uninit: &'a [MaybeUninit<T>]) -> Self {
+        let ptr = uninit as *const [MaybeUninit<T>] as *mut [T];
+        make!(Self; unsafe { NonNull::new_unchecked(ptr) })
+    }
+}
+
+impl<'a, T> From<UninitRef<'a, [T]>> for &'a [MaybeUninit<T>] {
+    #[inline(always)]
+    fn from(uninit: UninitRef<'a, [T]>) -> Self {
+        uninit.into_uninit_slice()
+    }
+}
+
+impl<'a, T> From<&'a mut [MaybeUninit<T>]> for UninitMut<'a, [T]> {
+    #[inline(always)]
+    fn from(uninit: &'a mut [MaybeUninit<T>]) -> Self {
+        let ptr = uninit as *mut [MaybeUninit<T>] as *mut [T];
+        make!(Self; unsafe { NonNull::new_unchecked(ptr) })
+    }
+}
+
+impl<'a, T> From<UninitMut<'a, [T]>> for &'a mut [MaybeUninit<T>] {
+    #[inline(always)]
+    fn from(uninit: UninitMut<'a, [T]>) -> Self {
+        uninit.into_uninit_slice()
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a [MaybeUninit<T>; N]> for UninitRef<'a, [T; N]> {
+    #[inline(always)]
+    fn from(uninit: &'a [MaybeUninit<T>; N]) -> Self {
+        make!(Self; NonNull::from(uninit).cast())
+    }
+}
+
+impl<'a, T, const N: usize> From<UninitRef<'a, [T; N]>> for &'a [MaybeUninit<T>; N] {
+    #[inline(always)]
+    fn from(uninit: UninitRef<'a, [T; N]>) -> Self {
+        unsafe { uninit.ptr.cast().as_ref() }
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a mut [MaybeUninit<T>; N]> for UninitMut<'a, [T; N]> {
+    #[inline(always)]
+    fn from(uninit: &'a mut [MaybeUninit<T>; N]) -> Self {
+        make!(Self; NonNull::from(uninit).cast())
+    }
+}
+
+impl<'a, T, const N: usize> From<UninitMut<'a, [T; N]>> for &'a mut [MaybeUninit<T>; N] {
+    #[inline(always)]
+    fn from(uninit: UninitMut<'a, [T; N]>) -> Self {
+        unsafe { uninit.ptr.cast().as_mut() }
+    }
+}
+
+macro_rules! impl_iter {
+    ($name:ident<$a:lifetime, $T:ident>($inner:ty) => $item:ty) => {
+        pub struct $name<$a, $T>($inner);
+
+        impl<$a, $T> Iterator for $name<$a, $T> {
+            type Item = $item;
+
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next().map(From::from)
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<$a, $T> DoubleEndedIterator for $name<$a, $T> {
+            #[inline(always)]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back().map(From::from)
+            }
+        }
+
+        impl<$a, $T> ExactSizeIterator for $name<$a, $T> {}
+        impl<$a, $T> FusedIterator for $name<$a, $T> {}
+
+        impl<$a, $T> fmt::Debug for $name<$a, $T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.pad(core::any::type_name::<Self>())
+            }
+        }
+    };
+}
+
+impl_iter!(Iter<'a, T>(slice::Iter<'a, MaybeUninit<T>>) => UninitRef<'a, T>);
+impl_iter!(IterMut<'a, T>(slice::IterMut<'a, MaybeUninit<T>>) => UninitMut<'a, T>);
+impl_iter!(ChunksMut<'a, T>(slice::ChunksMut<'a, MaybeUninit<T>>) => UninitMut<'a, [T]>);
+
+#[cold]
+#[inline(never)]
+fn panic_too_large() -> ! {
+    panic!("slice is too large to be allocated")
+}
@@ -0,0 +1,101 @@
+//! Unsizing coercions for places, as a stable polyfill for `CoerceUnsized`.
+//!
+//! A [`Coercion<T, U>`] witnesses that a pointer to `T` can be turned into a pointer
+//! to `U`, e.g. `[T; N]` into `[T]` or `F: FnMut()` into `dyn FnMut()`. They are built
+//! by the [`coercion!`](crate::coercion!) macro, and consumed by [`UnsizePlace`] and
+//! [`Init::unsize`](crate::Init::unsize).
+
+use core::fmt;
+
+use crate::place::Place;
+
+/// A pointer coercion from `T` to `U`, preserving the address of the pointee.
+pub struct Coercion<T: ?Sized, U: ?Sized> {
+    coerce: fn(*mut T) -> *mut U,
+}
+
+impl<T: ?Sized, U: ?Sized> Coercion<T, U> {
+    /// # Safety
+    ///
+    /// `coerce` must return its argument, with valid metadata for `U`.
+    #[inline(always)]
+    pub const unsafe fn new(coerce: fn(*mut T) -> *mut U) -> Self {
+        Self { coerce }
+    }
+
+    #[inline(always)]
+    pub fn coerce(self, ptr: *mut T) -> *mut U {
+        (self.coerce)(ptr)
+    }
+}
+
+impl<T: ?Sized> Coercion<T, T> {
+    #[inline(always)]
+    pub const fn identity() -> Self {
+        // SAFETY: the pointer is returned as-is.
+        unsafe { Self::new(|ptr| ptr) }
+    }
+}
+
+impl<T, const N: usize> Coercion<[T; N], [T]> {
+    #[inline(always)]
+    pub const fn to_slice() -> Self {
+        // SAFETY: this is a built-in unsizing coercion.
+        unsafe { Self::new(|ptr| ptr) }
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Copy for Coercion<T, U> {}
+impl<T: ?Sized, U: ?Sized> Clone for Coercion<T, U> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> fmt::Debug for Coercion<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(core::any::type_name::<Self>())
+    }
+}
+
+/// Creates a [`Coercion`] for any built-in unsizing coercion.
+///
+/// The target type can be given explicitly, which helps type inference when it is
+/// only known from the surrounding context.
+///
+/// ```
+/// use tinit::{coercion, Init, Own};
+///
+/// let mut count = 0;
+/// let slot = &mut tinit::stack_slot();
+/// let own = Own::new_in(slot, || count += 1);
+/// let mut own = Init::unsize(own, coercion!(dyn FnMut() + '_));
+/// own();
+/// own();
+/// drop(own);
+/// assert_eq!(count, 2);
+/// ```
+#[macro_export]
+macro_rules! coercion {
+    () => {
+        // SAFETY: the only coercions from `*mut T` to `*mut U` are unsizing
+        // coercions, which preserve the address.
+        unsafe { $crate::unsize::Coercion::new(|ptr| ptr) }
+    };
+    ($target:ty) => {
+        // SAFETY: see above.
+        unsafe { $crate::unsize::Coercion::<_, $target>::new(|ptr| ptr) }
+    };
+}
+
+/// A place that can be unsized to a place of `U`.
+///
+/// # Safety
+///
+/// `unsize_place` must return a place to the same memory, with the same ownership.
+pub unsafe trait UnsizePlace<U: ?Sized>: Place {
+    type Unsized: Place<Target = U>;
+
+    fn unsize_place(self, coercion: Coercion<Self::Target, U>) -> Self::Unsized;
+}
@@ -0,0 +1,136 @@
+//! In-place construction of [`Vec`] elements.
+
+use core::mem::ManuallyDrop;
+use core::{ptr, slice};
+
+use alloc::vec::Vec;
+
+use crate::place::Place;
+use crate::uninit::{UninitMut, UninitRef};
+
+/// Extension methods to emplace elements into a [`Vec`].
+pub trait VecExt<T> {
+    /// Returns the place of a new element at the end of the vector.
+    fn emplace(&mut self) -> VecHole<'_, T>;
+
+    /// Returns the place of a new element at position `pos`, shifting all elements after it.
+    fn emplace_at(&mut self, pos: usize) -> VecHole<'_, T>;
+}
+
+impl<T> VecExt<T> for Vec<T> {
+    #[inline]
+    fn emplace(&mut self) -> VecHole<'_, T> {
+        let pos = self.len();
+        unsafe { VecHole::open(self, pos) }
+    }
+
+    #[inline]
+    fn emplace_at(&mut self, pos: usize) -> VecHole<'_, T> {
+        let len = self.len();
+        if pos > len {
+            panic_out_of_bounds(pos, len)
+        } else {
+            unsafe { VecHole::open(self, pos) }
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn panic_out_of_bounds(pos: usize, len: usize) -> ! {
+    panic!("insertion index (is {pos}) should be <= len (is {len})")
+}
+
+/// The place of a new element in the spare capacity of a [`Vec`].
+///
+/// Finishing the place bumps the length of the vector; dropping it closes the hole.
+pub struct VecHole<'a, T> {
+    vec: &'a mut Vec<T>,
+    pos: usize,
+    // Number of elements moved after the hole.
+    suffix_len: usize,
+}
+
+impl<'a, T> VecHole<'a, T> {
+    // SAFETY: `pos <= vec.len()`
+    unsafe fn open(vec: &'a mut Vec<T>, pos: usize) -> Self {
+        vec.reserve(1);
+        let suffix_len = vec.len() - pos;
+        unsafe {
+            // 'Pre-poop our pants' so that leaking this place leaks all moved elements.
+            vec.set_len(pos);
+            // Move suffix one element to the right to open the hole
+            let ptr = vec.as_mut_ptr().add(pos);
+            ptr::copy(ptr, ptr.add(1), suffix_len);
+        }
+        Self { vec, pos, suffix_len }
+    }
+
+    #[inline(always)]
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline(always)]
+    pub fn split_ref(&self) -> (&[T], &[T]) {
+        let ptr = self.vec.as_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts(ptr, self.pos),
+                slice::from_raw_parts(ptr.add(self.pos + 1), self.suffix_len),
+            )
+        }
+    }
+
+    #[inline(always)]
+    pub fn split_mut(&mut self) -> (&mut [T], &mut [T]) {
+        let ptr = self.vec.as_mut_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr, self.pos),
+                slice::from_raw_parts_mut(ptr.add(self.pos + 1), self.suffix_len),
+            )
+        }
+    }
+}
+
+unsafe impl<'a, T: 'a> Place for VecHole<'a, T> {
+    type Target = T;
+    type Init = &'a mut T;
+
+    #[inline(always)]
+    fn deref_place(&self) -> UninitRef<'_, Self::Target> {
+        unsafe { UninitRef::new_unchecked(self.vec.as_ptr().add(self.pos)) }
+    }
+
+    #[inline(always)]
+    fn deref_place_mut(&mut self) -> UninitMut<'_, Self::Target> {
+        unsafe { UninitMut::new_unchecked(self.vec.as_mut_ptr().add(self.pos)) }
+    }
+
+    #[inline(always)]
+    unsafe fn assume_init(self) -> Self::Init {
+        // Disable drop impl.
+        let this = ManuallyDrop::new(self);
+        let (pos, suffix_len) = (this.pos, this.suffix_len);
+        // SAFETY: `this` isn't accessed nor dropped after this line.
+        let vec = unsafe { ptr::read(&this.vec) };
+        unsafe {
+            // Element is initialized, put back correct length.
+            vec.set_len(pos + 1 + suffix_len);
+            vec.get_unchecked_mut(pos)
+        }
+    }
+}
+
+impl<'a, T> Drop for VecHole<'a, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        // Shift back suffix and fix the length
+        unsafe {
+            let ptr = self.vec.as_mut_ptr().add(self.pos);
+            ptr::copy(ptr.add(1), ptr, self.suffix_len);
+            self.vec.set_len(self.pos + self.suffix_len);
+        }
+    }
+}
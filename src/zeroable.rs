@@ -0,0 +1,87 @@
+//! Types which are valid when all their bytes are zero.
+//!
+//! Such values can be initialized with [`Slot::zeroed`](crate::Slot::zeroed), or
+//! [`Emplace::emplace_zeroed`](crate::Emplace::emplace_zeroed) which lets heap places
+//! request already-zeroed memory from the allocator.
+
+use core::cell::{Cell, UnsafeCell};
+use core::marker::{PhantomData, PhantomPinned};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::num::{NonZero, Saturating, Wrapping};
+use core::ptr::NonNull;
+use core::sync::atomic;
+
+/// A type for which the all-zero bit pattern is a valid value.
+///
+/// Use the [`derive_zeroable!`](crate::derive_zeroable!) macro to implement it for structs.
+///
+/// # Safety
+///
+/// Any memory filled with zero bytes must be a valid instance of `Self`.
+pub unsafe trait Zeroable {}
+
+macro_rules! impl_zeroable {
+    ($($ty:ty),* $(,)?) => {
+        $(unsafe impl Zeroable for $ty {})*
+    };
+}
+
+impl_zeroable!(u8, u16, u32, u64, u128, usize);
+impl_zeroable!(i8, i16, i32, i64, i128, isize);
+impl_zeroable!(f32, f64, bool, char, (), PhantomPinned);
+
+#[cfg(target_has_atomic = "8")]
+impl_zeroable!(atomic::AtomicBool, atomic::AtomicU8, atomic::AtomicI8);
+#[cfg(target_has_atomic = "16")]
+impl_zeroable!(atomic::AtomicU16, atomic::AtomicI16);
+#[cfg(target_has_atomic = "32")]
+impl_zeroable!(atomic::AtomicU32, atomic::AtomicI32);
+#[cfg(target_has_atomic = "64")]
+impl_zeroable!(atomic::AtomicU64, atomic::AtomicI64);
+#[cfg(target_has_atomic = "ptr")]
+impl_zeroable!(atomic::AtomicUsize, atomic::AtomicIsize);
+
+#[cfg(target_has_atomic = "ptr")]
+unsafe impl<T> Zeroable for atomic::AtomicPtr<T> {}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+unsafe impl<T: Zeroable> Zeroable for [T] {}
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+unsafe impl<T: ?Sized> Zeroable for PhantomData<T> {}
+
+unsafe impl<T: Zeroable + ?Sized> Zeroable for ManuallyDrop<T> {}
+unsafe impl<T: Zeroable + ?Sized> Zeroable for Cell<T> {}
+unsafe impl<T: Zeroable + ?Sized> Zeroable for UnsafeCell<T> {}
+unsafe impl<T: Zeroable> Zeroable for Wrapping<T> {}
+unsafe impl<T: Zeroable> Zeroable for Saturating<T> {}
+
+// Null pointers. Wide pointers are excluded, as their metadata may be invalid.
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+
+// `None` is guaranteed to be all zeroes for these.
+unsafe impl<T> Zeroable for Option<&T> {}
+unsafe impl<T> Zeroable for Option<&mut T> {}
+unsafe impl<T> Zeroable for Option<NonNull<T>> {}
+
+impl_zeroable!(
+    Option<NonZero<u8>>, Option<NonZero<u16>>, Option<NonZero<u32>>,
+    Option<NonZero<u64>>, Option<NonZero<u128>>, Option<NonZero<usize>>,
+    Option<NonZero<i8>>, Option<NonZero<i16>>, Option<NonZero<i32>>,
+    Option<NonZero<i64>>, Option<NonZero<i128>>, Option<NonZero<isize>>,
+);
+
+macro_rules! impl_zeroable_tuple {
+    ($($T:ident)*) => {
+        unsafe impl<$($T: Zeroable),*> Zeroable for ($($T,)*) {}
+    };
+}
+
+impl_zeroable_tuple!(A);
+impl_zeroable_tuple!(A B);
+impl_zeroable_tuple!(A B C);
+impl_zeroable_tuple!(A B C D);
+impl_zeroable_tuple!(A B C D E);
+impl_zeroable_tuple!(A B C D E F);
+impl_zeroable_tuple!(A B C D E F G);
+impl_zeroable_tuple!(A B C D E F G H);
@@ -0,0 +1,105 @@
+//! Initializes multi-megabyte values on threads with tiny stacks.
+//!
+//! A regression reintroducing a temporary copy either overflows the stack, aborting the
+//! whole test binary, or exceeds the stack budget checked by [`support::on_small_stack`].
+//! The budget is only checked where the workloads sample the stack, so a smaller copy
+//! that is gone before the next sample isn't caught.
+
+mod support;
+
+use support::{on_small_stack, BIG};
+use tinit::project::Projection;
+use tinit::slice::Slice;
+use tinit::{emplace, project, Emplace, EmplaceSlice, Slot};
+
+type Big = [u64; BIG / 8];
+
+#[test]
+fn emplace_macro() {
+    let big = on_small_stack(|sampler| {
+        emplace!(Box::<Big>::emplace() => out {
+            sampler.sample();
+            let mut slice = Slice::new(out);
+            while !slice.is_full() {
+                let i = slice.len() as u64;
+                slice.push(i);
+            }
+            sampler.sample();
+            slice.assert_full()
+        })
+    });
+    assert_eq!(big[BIG / 8 - 1], BIG as u64 / 8 - 1);
+}
+
+// Without optimizations, the array is built on the stack before being moved into the
+// box, which overflows the stack; run it with `cargo test --release`.
+#[test]
+#[cfg_attr(debug_assertions, ignore = "needs optimizations")]
+fn slot_set() {
+    let big = on_small_stack(|_| Box::<[u8; BIG]>::emplace().set([0xAB; BIG]));
+    assert!(big.iter().all(|b| *b == 0xAB));
+}
+
+#[test]
+fn slot_with() {
+    let big = on_small_stack(|sampler| {
+        Box::<Big>::emplace().with(|out| {
+            sampler.sample();
+            let mut slice = Slice::new(out);
+            while !slice.is_full() {
+                slice.push(7);
+            }
+            slice.assert_full()
+        })
+    });
+    assert!(big.iter().all(|n| *n == 7));
+}
+
+#[test]
+fn slice_fill() {
+    let big = on_small_stack(|sampler| {
+        let mut slice = Slice::new(Box::<[u64]>::emplace_slice(BIG / 8));
+        while !slice.is_full() {
+            slice.emplace().set(3);
+        }
+        sampler.sample();
+        tinit::Init::finalize(slice.assert_full())
+    });
+    assert_eq!(big.len(), BIG / 8);
+
+    let big = on_small_stack(|sampler| {
+        Box::<Big>::emplace().init_from_fn(|i| {
+            if i % 4096 == 0 {
+                sampler.sample();
+            }
+            i as u64
+        })
+    });
+    assert_eq!(big[4096], 4096);
+}
+
+#[test]
+fn nested_projection() {
+    project! {
+        #[project = StateFields]
+        struct State {
+            tick: u64,
+            grid: [[u8; 1024]; BIG / 1024],
+        }
+    }
+
+    let state = on_small_stack(|sampler| {
+        emplace!(Box::<State>::emplace() => out {
+            let mut state = Projection::new(out);
+            let fields = state.fields();
+            fields.tick.set(1);
+            fields.grid.with(|out| {
+                sampler.sample();
+                out.init_from_fn(|_| [0xFF; 1024])
+            });
+            state.finish()
+        })
+    });
+    assert_eq!(state.tick, 1);
+    assert_eq!(state.grid[BIG / 1024 - 1][1023], 0xFF);
+}
@@ -0,0 +1,99 @@
+//! Helpers for checking that large values are initialized without stack copies.
+//!
+//! Workloads run on a thread whose stack is much smaller than the values they build, so
+//! any temporary copy overflows the stack and aborts the test binary. On top of that,
+//! a [`StackSampler`] records how deep the stack is at the points where the workload
+//! samples it, which catches temporaries that are too small to overflow but still larger
+//! than expected, as long as they are live at one of the samples.
+
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::hint::black_box;
+use std::thread;
+
+/// Stack size of the threads running the workloads.
+pub const SMALL_STACK: usize = 256 * 1024;
+
+/// Maximum stack usage allowed at any sample point.
+///
+/// This is generous enough for unoptimized builds, and still far below the size of the
+/// values under test.
+pub const STACK_BUDGET: usize = 32 * 1024;
+
+/// Size of the values under test, large enough to overflow [`SMALL_STACK`] many times.
+pub const BIG: usize = 8 * 1024 * 1024;
+
+/// Samples the stack depth of a thread, relative to the point it was created.
+///
+/// This is not a high-water mark: only the depths at the calls to [`sample`](Self::sample)
+/// are recorded, so a temporary that is dropped before the next sample goes unnoticed.
+pub struct StackSampler {
+    base: usize,
+    deepest: Cell<usize>,
+}
+
+impl StackSampler {
+    #[inline(always)]
+    fn new() -> Self {
+        let base = stack_addr();
+        Self {
+            base,
+            deepest: Cell::new(base),
+        }
+    }
+
+    /// Runs `f` in its own stack frame, so that all its locals are below the base.
+    #[inline(never)]
+    fn run<R>(&self, f: impl FnOnce(&Self) -> R) -> R {
+        let result = f(self);
+        self.sample();
+        result
+    }
+
+    /// Records the current stack depth.
+    #[inline(never)]
+    pub fn sample(&self) {
+        let addr = stack_addr();
+        // Stacks grow downwards on every supported target.
+        if addr < self.deepest.get() {
+            self.deepest.set(addr);
+        }
+    }
+
+    /// The deepest stack usage sampled so far, in bytes.
+    pub fn deepest_sample(&self) -> usize {
+        self.base - self.deepest.get()
+    }
+}
+
+#[inline(always)]
+fn stack_addr() -> usize {
+    let marker = 0u8;
+    black_box(&marker) as *const u8 as usize
+}
+
+/// Runs `f` on a thread with a [`SMALL_STACK`], and checks that the stack usage it
+/// sampled stays within [`STACK_BUDGET`].
+#[track_caller]
+pub fn on_small_stack<R: Send>(f: impl FnOnce(&StackSampler) -> R + Send) -> R {
+    let (result, deepest) = thread::scope(|scope| {
+        thread::Builder::new()
+            .name("small-stack".into())
+            .stack_size(SMALL_STACK)
+            .spawn_scoped(scope, || {
+                let sampler = StackSampler::new();
+                let result = sampler.run(f);
+                (result, sampler.deepest_sample())
+            })
+            .expect("failed to spawn thread")
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    });
+
+    assert!(
+        deepest <= STACK_BUDGET,
+        "used {deepest} bytes of stack, more than the budget of {STACK_BUDGET} bytes",
+    );
+    result
+}